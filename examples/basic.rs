@@ -30,6 +30,7 @@ fn perform_query(connection: &mut Connection) -> error::Result<()> {
         .execute()?;
 
     for r in res.iter::<Row>()? {
+        let r = r?;
         let node_a: Node = r.get_val_by_column("a")?;
         let rel_e: Relation = r.get_val_by_column("e")?;
         let node_b: Node = r.get_val_by_column("b")?;
@@ -44,11 +45,11 @@ fn perform_query(connection: &mut Connection) -> error::Result<()> {
 
 fn main() -> Result<(), Box<dyn Error>> {
     let database_path = "test2";
-    let mut db = Database::builder(database_path)
+    let db = Database::builder(database_path)
         .with_log_level(kuzu_rs::database::LogLevel::Debug)
         .build()?;
 
-    let mut connection = Connection::new(&mut db)?;
+    let mut connection = Connection::new(&db)?;
 
     create_tables(&mut connection)?;
     load_data(&mut connection)?;
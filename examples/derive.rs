@@ -3,11 +3,12 @@ use std::error::Error;
 use kuzu_rs::connection::Connection;
 use kuzu_rs::database::Database;
 use kuzu_rs::error;
-use kuzu_rs::types::decode::Decode;
 use kuzu_rs::types::row::Row;
-use kuzu_rs::types::value::{KuzuValue, Node, Relation, Struct};
+use kuzu_rs::types::value::{InternalId, Node, Relation};
 
-use kuzu_rs::macros::{FromKuzuRow, FromKuzuStruct};
+use kuzu_rs::macros::{
+    FromKuzuNode, FromKuzuRel, FromKuzuRow, FromKuzuStruct, IntoParams, KuzuNodeTable, KuzuRelTable,
+};
 
 #[derive(FromKuzuRow, Debug)]
 struct MyRow {
@@ -25,12 +26,55 @@ struct Person {
     last: String,
 }
 
+#[derive(FromKuzuRow, Debug)]
+struct Follower {
+    #[kuzu(rename = "b.name")]
+    name: String,
+    #[kuzu(rename = "count(*)", default)]
+    followers: i64,
+    #[kuzu(skip)]
+    note: Option<String>,
+}
+
+#[derive(IntoParams, FromKuzuNode, KuzuNodeTable, Debug, Clone)]
+struct User {
+    #[kuzu(primary_key)]
+    name: String,
+    age: i64,
+}
+
+#[derive(FromKuzuNode, KuzuNodeTable, Debug)]
+struct City {
+    #[kuzu(primary_key)]
+    name: String,
+    population: i64,
+}
+
+#[derive(FromKuzuRel, KuzuRelTable, Debug)]
+#[kuzu(from = User, to = User)]
+struct Follows {
+    #[kuzu(src)]
+    follower: InternalId,
+    #[kuzu(dst)]
+    followee: Option<InternalId>,
+    since: i64,
+}
+
+#[derive(KuzuRelTable)]
+#[kuzu(from = User, to = City)]
+struct LivesIn {}
+
+#[derive(FromKuzuRow, Debug)]
+struct TypedRow {
+    a: User,
+    e: Follows,
+}
+
 fn create_tables(connection: &mut Connection) -> error::Result<()> {
-    connection.query("CREATE NODE TABLE User(name STRING, age INT64, PRIMARY KEY (name));")?;
-    connection
-        .query("CREATE NODE TABLE City(name STRING, population INT64, PRIMARY KEY (name));")?;
-    connection.query("CREATE REL TABLE Follows(FROM User TO User, since INT64);")?;
-    connection.query("CREATE REL TABLE LivesIn(FROM User TO City);")?;
+    connection.create_table::<User>()?;
+    connection.create_table::<City>()?;
+    connection.create_table::<Follows>()?;
+    connection.create_table::<LivesIn>()?;
     Ok(())
 }
 
@@ -42,6 +86,14 @@ fn load_data(connection: &mut Connection) -> error::Result<()> {
     Ok(())
 }
 
+fn insert_user(connection: &mut Connection, user: &User) -> error::Result<()> {
+    connection
+        .prepare("CREATE (:User {name: $name, age: $age});")?
+        .bind_all(user)?
+        .execute()?;
+    Ok(())
+}
+
 fn perform_query(connection: &mut Connection) -> error::Result<()> {
     let res = connection
         .prepare("MATCH (a:User)<-[e:Follows]-(b:User) WHERE a.age > $age RETURN a, e, b")?
@@ -49,14 +101,42 @@ fn perform_query(connection: &mut Connection) -> error::Result<()> {
         .execute()?;
 
     for r in res.iter::<MyRow>()? {
+        let r = r?;
         println!("User: {:?}", r.a);
         println!("Follows: {:?}", r.e);
         println!("User: {:?}", r.b);
     }
 
+    let res = connection.query("MATCH (a:User)-[e:Follows]->(:User) RETURN a, e;")?;
+
+    for r in res.iter::<TypedRow>()? {
+        let r = r?;
+        println!(
+            "{} ({}) follows since {}: {:?} -> {:?}",
+            r.a.name, r.a.age, r.e.since, r.e.follower, r.e.followee
+        );
+    }
+
+    let res = connection.query("MATCH (a:User)<-[:Follows]-(b:User) RETURN b.name, count(*);")?;
+
+    for r in res.iter::<Follower>()? {
+        let r = r?;
+        println!("{} follows {} users {:?}", r.name, r.followers, r.note);
+    }
+
+    let res = connection.query("MATCH (a:User)-[:LivesIn]->(c:City) RETURN a.name, c;")?;
+
+    for r in res.iter::<Row>()? {
+        let r = r?;
+        let name: String = r.get_val(0)?;
+        let city: City = r.get_val(1)?;
+        println!("{} lives in {} ({})", name, city.name, city.population);
+    }
+
     let res = connection.query("RETURN {first:'Xiyang', last:'Feng'};")?;
 
     for r in res.iter::<Row>()? {
+        let r = r?;
         let person: Person = r.get_val(0)?;
         dbg!(&person);
     }
@@ -66,14 +146,21 @@ fn perform_query(connection: &mut Connection) -> error::Result<()> {
 
 fn main() -> Result<(), Box<dyn Error>> {
     let database_path = "test2";
-    let mut db = Database::builder(database_path)
+    let db = Database::builder(database_path)
         .with_log_level(kuzu_rs::database::LogLevel::Debug)
         .build()?;
 
-    let mut connection = Connection::new(&mut db)?;
+    let mut connection = Connection::new(&db)?;
 
     create_tables(&mut connection)?;
     load_data(&mut connection)?;
+    insert_user(
+        &mut connection,
+        &User {
+            name: "Noura".to_owned(),
+            age: 25,
+        },
+    )?;
     perform_query(&mut connection)?;
 
     Ok(())
@@ -28,6 +28,7 @@ fn print_data(tx: &mut Transaction) -> kuzu_rs::error::Result<()> {
     )?;
 
     for r in res.iter::<Row>()? {
+        let r = r?;
         let name: String = r.get_val_by_column("u.name")?;
         let balance: f32 = r.get_val_by_column("u.balance")?;
 
@@ -39,11 +40,11 @@ fn print_data(tx: &mut Transaction) -> kuzu_rs::error::Result<()> {
 
 fn main() -> Result<(), Box<dyn Error>> {
     let database_path = "test2";
-    let mut db = Database::builder(database_path)
+    let db = Database::builder(database_path)
         .with_log_level(kuzu_rs::database::LogLevel::Debug)
         .build()?;
 
-    let mut conn = Connection::new(&mut db)?;
+    let mut conn = Connection::new(&db)?;
 
     create_table(&conn)?;
     insert_data(&conn)?;
@@ -56,12 +57,16 @@ fn main() -> Result<(), Box<dyn Error>> {
 
         println!("After rollback:");
         update_data(&mut tx)?;
-        tx.rollback();
+        tx.rollback()?;
         print_data(&mut tx)?;
+    }
+
+    conn.with_transaction(TransactionType::ReadWrite, update_data)?;
+
+    {
+        let mut tx = conn.transaction(TransactionType::Readonly)?;
 
         println!("After commit:");
-        update_data(&mut tx)?;
-        tx.commit();
         print_data(&mut tx)?;
     }
 
@@ -0,0 +1,234 @@
+use proc_macro2::Span;
+use syn::{Attribute, Field, LitStr, Path};
+
+/// The `#[kuzu(...)]` attributes of the struct a macro is derived for.
+pub(crate) struct ContainerAttrs {
+    /// The path of the `kuzu_rs` crate used by the generated code.
+    pub(crate) krate: Path,
+    /// The name of the table, instead of the struct name.
+    pub(crate) rename: Option<String>,
+    /// The node table the rels of a rel table start from.
+    pub(crate) from: Option<Path>,
+    /// The node table the rels of a rel table end at.
+    pub(crate) to: Option<Path>,
+    /// The name and span of every attribute of the struct but `crate`, to report the ones a derive doesn't support.
+    used: Vec<(&'static str, Span)>,
+}
+
+impl ContainerAttrs {
+    /// Parses the `#[kuzu(...)]` attributes of a struct.
+    pub(crate) fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut krate = None;
+        let mut rename = None;
+        let mut from = None;
+        let mut to = None;
+        let mut used = vec![];
+        for attr in attrs.iter().filter(|attr| is_kuzu_attr(attr)) {
+            attr.parse_nested_meta(|meta| {
+                let span = meta
+                    .path
+                    .get_ident()
+                    .map_or_else(Span::call_site, |ident| ident.span());
+                let name = if meta.path.is_ident("crate") {
+                    krate = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                    return Ok(());
+                } else if meta.path.is_ident("rename") {
+                    rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    "rename"
+                } else if meta.path.is_ident("from") {
+                    from = Some(meta.value()?.parse()?);
+                    "from"
+                } else if meta.path.is_ident("to") {
+                    to = Some(meta.value()?.parse()?);
+                    "to"
+                } else {
+                    return Err(meta.error(
+                        "unknown kuzu attribute, expected one of `crate`, `rename`, `from`, `to`",
+                    ));
+                };
+                used.push((name, span));
+                Ok(())
+            })?;
+        }
+        Ok(Self {
+            krate: krate.unwrap_or_else(|| syn::parse_quote!(::kuzu_rs)),
+            rename,
+            from,
+            to,
+            used,
+        })
+    }
+
+    /// Returns an error at the first attribute of the struct that is not in the `supported` attributes of `derive`.
+    /// `crate` is supported by every derive.
+    pub(crate) fn check_supported(&self, derive: &str, supported: &[&str]) -> syn::Result<()> {
+        check_supported(&self.used, derive, supported)
+    }
+}
+
+/// A field holding a value of a node or rel other than its properties.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MetaField {
+    /// The internal id of a node.
+    Id,
+    /// The label of a node or rel.
+    Label,
+    /// The internal id of the source node of a rel.
+    Src,
+    /// The internal id of the destination node of a rel.
+    Dst,
+}
+
+impl MetaField {
+    /// Returns the name of the attribute marking the field.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            MetaField::Id => "id",
+            MetaField::Label => "label",
+            MetaField::Src => "src",
+            MetaField::Dst => "dst",
+        }
+    }
+}
+
+/// The `#[kuzu(...)]` attributes of a field.
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    /// The name of the column, struct field, property or parameter, instead of the Rust field name.
+    pub(crate) rename: Option<String>,
+    /// Whether `Default::default()` is used when the column, struct field or property is missing.
+    pub(crate) default: bool,
+    /// Whether the field is left out, and set to `Default::default()` when decoding.
+    pub(crate) skip: bool,
+    /// The function decoding the `KuzuValue` into the field, instead of `Decode`.
+    pub(crate) with: Option<Path>,
+    /// Whether the field is decoded from the whole row, struct, node or rel, instead of a single value.
+    pub(crate) flatten: bool,
+    /// The value of the node or rel the field holds instead of a property.
+    pub(crate) meta: Option<MetaField>,
+    /// Whether the property is the primary key of a node table.
+    pub(crate) primary_key: bool,
+    /// The name and span of every attribute of the field, to report the ones a derive doesn't support.
+    used: Vec<(&'static str, Span)>,
+}
+
+impl FieldAttrs {
+    /// Parses the `#[kuzu(...)]` attributes of a field.
+    pub(crate) fn parse(field: &Field) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        let mut num_metas = 0;
+        for attr in field.attrs.iter().filter(|attr| is_kuzu_attr(attr)) {
+            attr.parse_nested_meta(|meta| {
+                let meta_field = [
+                    MetaField::Id,
+                    MetaField::Label,
+                    MetaField::Src,
+                    MetaField::Dst,
+                ]
+                .into_iter()
+                .find(|meta_field| meta.path.is_ident(meta_field.name()));
+
+                let span = meta.path.get_ident().map_or_else(Span::call_site, |ident| ident.span());
+                let name = if meta.path.is_ident("rename") {
+                    attrs.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    "rename"
+                } else if meta.path.is_ident("default") {
+                    attrs.default = true;
+                    "default"
+                } else if meta.path.is_ident("skip") {
+                    attrs.skip = true;
+                    "skip"
+                } else if meta.path.is_ident("with") {
+                    attrs.with = Some(meta.value()?.parse()?);
+                    "with"
+                } else if meta.path.is_ident("flatten") {
+                    attrs.flatten = true;
+                    "flatten"
+                } else if meta.path.is_ident("primary_key") {
+                    attrs.primary_key = true;
+                    "primary_key"
+                } else if let Some(meta_field) = meta_field {
+                    attrs.meta = Some(meta_field);
+                    num_metas += 1;
+                    meta_field.name()
+                } else {
+                    return Err(meta.error(
+                        "unknown kuzu attribute, expected one of `rename`, `default`, `skip`, `with`, `flatten`, `id`, `label`, `src`, `dst`, `primary_key`",
+                    ));
+                };
+                attrs.used.push((name, span));
+                Ok(())
+            })?;
+        }
+
+        let conflict = match &attrs {
+            FieldAttrs { skip: true, .. }
+                if attrs.rename.is_some()
+                    || attrs.default
+                    || attrs.with.is_some()
+                    || attrs.flatten
+                    || attrs.meta.is_some()
+                    || attrs.primary_key =>
+            {
+                Some("`skip` cannot be combined with other kuzu attributes")
+            }
+            FieldAttrs { flatten: true, .. }
+                if attrs.rename.is_some()
+                    || attrs.default
+                    || attrs.with.is_some()
+                    || attrs.meta.is_some()
+                    || attrs.primary_key =>
+            {
+                Some("`flatten` cannot be combined with `rename`, `default`, `with`, `primary_key` or a node or rel value")
+            }
+            FieldAttrs { meta: Some(_), .. }
+                if num_metas > 1
+                    || attrs.rename.is_some()
+                    || attrs.default
+                    || attrs.with.is_some()
+                    || attrs.primary_key =>
+            {
+                Some("`id`, `label`, `src` and `dst` cannot be combined with other kuzu attributes")
+            }
+            _ => None,
+        };
+        match conflict {
+            Some(message) => Err(syn::Error::new_spanned(field, message)),
+            None => Ok(attrs),
+        }
+    }
+
+    /// Returns an error at the first attribute of the field that is not in the `supported` attributes of `derive`.
+    pub(crate) fn check_supported(&self, derive: &str, supported: &[&str]) -> syn::Result<()> {
+        check_supported(&self.used, derive, supported)
+    }
+
+    /// Returns the name of the column, struct field, property or parameter of the field.
+    pub(crate) fn name(&self, ident: &syn::Ident) -> String {
+        use syn::ext::IdentExt;
+
+        self.rename
+            .clone()
+            .unwrap_or_else(|| ident.unraw().to_string())
+    }
+}
+
+/// Returns an error at the first of the `used` attributes that is not in the `supported` attributes of `derive`.
+fn check_supported(
+    used: &[(&'static str, Span)],
+    derive: &str,
+    supported: &[&str],
+) -> syn::Result<()> {
+    match used.iter().find(|(name, _)| !supported.contains(name)) {
+        Some((name, span)) => Err(syn::Error::new(
+            *span,
+            format!("`{name}` is not supported by {derive}"),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Returns whether the attribute is a `#[kuzu(...)]` attribute.
+pub(crate) fn is_kuzu_attr(attr: &Attribute) -> bool {
+    attr.path().is_ident("kuzu")
+}
@@ -0,0 +1,39 @@
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse_quote, Data, DeriveInput, Fields, Generics};
+
+/// Returns the fields of the struct, or an error naming the derive if it is not a struct
+/// with named or, if `allow_unnamed`, unnamed fields.
+pub(crate) fn struct_fields<'a>(
+    ast: &'a DeriveInput,
+    derive: &str,
+    allow_unnamed: bool,
+) -> syn::Result<&'a Fields> {
+    match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(_) => return Ok(&data.fields),
+            Fields::Unnamed(_) if allow_unnamed => return Ok(&data.fields),
+            _ => {}
+        },
+        Data::Enum(_) | Data::Union(_) => {}
+    }
+
+    let expected = match allow_unnamed {
+        true => "structs with named or unnamed fields",
+        false => "structs with named fields",
+    };
+    Err(syn::Error::new_spanned(
+        &ast.ident,
+        format!("{derive} can only be derived for {expected}"),
+    ))
+}
+
+/// Returns the generics with `bounds` added to every type parameter.
+pub(crate) fn with_bounds(generics: &Generics, bounds: &[TokenStream2]) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        for bound in bounds {
+            param.bounds.push(parse_quote!(#bound));
+        }
+    }
+    generics
+}
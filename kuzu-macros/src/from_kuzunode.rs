@@ -0,0 +1,20 @@
+use proc_macro::TokenStream;
+use syn::{self, DeriveInput};
+
+use crate::from_kuzustruct::{expand, Source};
+
+pub(super) fn from_kuzu_node_derive(item: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse_macro_input!(item);
+
+    expand(&ast, Source::Node)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+pub(super) fn from_kuzu_rel_derive(item: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse_macro_input!(item);
+
+    expand(&ast, Source::Rel)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
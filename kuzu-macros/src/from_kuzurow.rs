@@ -1,63 +1,162 @@
 use proc_macro::TokenStream;
-use quote::{__private::TokenStream as TokenStream2, quote};
-use syn::{self, Data, DeriveInput, FieldsNamed, FieldsUnnamed};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{self, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, Path};
 
-fn impl_for_named_from_kuzu_row(fields: &FieldsNamed) -> TokenStream2 {
-    let names = fields
+use crate::attr::{ContainerAttrs, FieldAttrs};
+use crate::common::{struct_fields, with_bounds};
+
+/// The field attributes supported by `FromKuzuRow`.
+const FIELD_ATTRS: &[&str] = &["rename", "default", "skip", "with", "flatten"];
+
+/// Decodes the value of a column, found by `lookup`, according to the attributes of the field,
+/// wrapping a decoding error into an `Error::ColumnDecodeError` naming the column.
+fn decode_column(
+    krate: &Path,
+    attrs: &FieldAttrs,
+    ty: &syn::Type,
+    lookup: TokenStream2,
+    column: TokenStream2,
+) -> TokenStream2 {
+    let decode_fn = match &attrs.with {
+        Some(path) => quote!(#path),
+        None => quote!(<#ty as #krate::types::decode::Decode>::decode_kuzuval),
+    };
+    let default_arm = attrs.default.then(|| {
+        quote! {
+            ::core::result::Result::Err(#krate::error::Error::ColumnNotFound(..)) => {
+                ::core::default::Default::default()
+            }
+        }
+    });
+
+    quote! {
+        match #lookup {
+            ::core::result::Result::Ok(value) => {
+                #decode_fn(::core::clone::Clone::clone(value)).map_err(|e| {
+                    #krate::error::Error::ColumnDecodeError {
+                        column: ::std::string::ToString::to_string(&#column),
+                        source: ::std::boxed::Box::new(e),
+                    }
+                })?
+            }
+            #default_arm
+            ::core::result::Result::Err(e) => return ::core::result::Result::Err(e),
+        }
+    }
+}
+
+fn impl_for_named_from_kuzu_row(krate: &Path, fields: &FieldsNamed) -> syn::Result<TokenStream2> {
+    let inits = fields
         .named
         .iter()
-        .map(|field| (field.ident.clone().unwrap()));
+        .map(|field| {
+            let attrs = FieldAttrs::parse(field)?;
+            attrs.check_supported("FromKuzuRow", FIELD_ATTRS)?;
+            let name = field.ident.clone().unwrap();
+            let column = attrs.name(&name);
 
-    quote! {
+            let value = if attrs.skip {
+                quote!(::core::default::Default::default())
+            } else if attrs.flatten {
+                quote!(::core::convert::TryFrom::try_from(
+                    ::core::clone::Clone::clone(&row)
+                )?)
+            } else {
+                decode_column(
+                    krate,
+                    &attrs,
+                    &field.ty,
+                    quote!(row.get_ref_by_column(#column)),
+                    quote!(#column),
+                )
+            };
+            Ok(quote!(#name: #value))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
         Self {
-            #(
-                #names: row.get_val_by_column(stringify!(#names))?
-            ),*
+            #(#inits),*
         }
-    }
+    })
 }
 
-fn impl_for_unnamed_from_kuzu_row(fields: &FieldsUnnamed) -> TokenStream2 {
-    let names = 0..(fields.unnamed.len());
+fn impl_for_unnamed_from_kuzu_row(
+    krate: &Path,
+    fields: &FieldsUnnamed,
+) -> syn::Result<TokenStream2> {
+    let mut idx = 0usize;
+    let inits = fields
+        .unnamed
+        .iter()
+        .map(|field| {
+            let attrs = FieldAttrs::parse(field)?;
+            attrs.check_supported("FromKuzuRow", FIELD_ATTRS)?;
+            if attrs.rename.is_some() || attrs.flatten {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "`rename` and `flatten` are only supported on named fields",
+                ));
+            }
+            if attrs.skip {
+                return Ok(quote!(::core::default::Default::default()));
+            }
+
+            let value = decode_column(
+                krate,
+                &attrs,
+                &field.ty,
+                quote!(row.get_ref(#idx)),
+                quote!(#idx),
+            );
+            idx += 1;
+            Ok(value)
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
 
-    quote! {
+    Ok(quote! {
         Self (
-            #(
-                row.get_val(#names)?
-            ),*
+            #(#inits),*
         )
-    }
+    })
 }
 
-pub fn from_kuzu_row_derive(item: TokenStream) -> TokenStream {
-    let ast: DeriveInput = syn::parse(item).unwrap();
-
-    let struct_name = ast.ident;
+fn expand(ast: &DeriveInput) -> syn::Result<TokenStream2> {
+    let attrs = ContainerAttrs::parse(&ast.attrs)?;
+    attrs.check_supported("FromKuzuRow", &[])?;
+    let krate = attrs.krate;
+    let struct_name = &ast.ident;
 
-    let struct_iteself = {
-        match ast.data {
-            Data::Struct(ref _s) => _s,
-            _ => unreachable!(),
-        }
+    let body = match struct_fields(ast, "FromKuzuRow", true)? {
+        Fields::Named(fields) => impl_for_named_from_kuzu_row(&krate, fields)?,
+        Fields::Unnamed(fields) => impl_for_unnamed_from_kuzu_row(&krate, fields)?,
+        Fields::Unit => unreachable!("rejected by struct_fields"),
     };
 
-    let body = {
-        match struct_iteself.fields {
-            syn::Fields::Named(ref fields) => impl_for_named_from_kuzu_row(fields),
-            syn::Fields::Unnamed(ref fields) => impl_for_unnamed_from_kuzu_row(fields),
-            _ => unreachable!(),
-        }
-    };
+    let generics = with_bounds(&ast.generics, &[quote!(#krate::types::decode::Decode)]);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    quote! {
-        impl TryFrom<Row> for #struct_name {
-            type Error = kuzu_rs::error::Error;
+    Ok(quote! {
+        impl #impl_generics ::core::convert::TryFrom<#krate::types::row::Row>
+            for #struct_name #ty_generics #where_clause
+        {
+            type Error = #krate::error::Error;
 
             #[inline]
-            fn try_from(row: Row) -> Result<Self, Self::Error> {
-                Ok(#body)
+            fn try_from(
+                row: #krate::types::row::Row,
+            ) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok(#body)
             }
         }
-    }
-    .into()
+    })
+}
+
+pub fn from_kuzu_row_derive(item: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse_macro_input!(item);
+
+    expand(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
@@ -1,65 +1,198 @@
 use proc_macro::TokenStream;
-use quote::{__private::TokenStream as TokenStream2, quote};
-use syn::{self, Data, DeriveInput, FieldsNamed, Ident, Type};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{self, DeriveInput, Fields, FieldsNamed, Path};
 
-fn impl_for_named_from_kuzu_struct(fields: &FieldsNamed) -> TokenStream2 {
-    let (names, tys): (Vec<Ident>, Vec<Type>) = fields
+use crate::attr::{ContainerAttrs, FieldAttrs};
+use crate::common::{struct_fields, with_bounds};
+
+/// The kind of value a derived `Decode` implementation decodes the fields of a struct from.
+#[derive(Clone, Copy)]
+pub(crate) enum Source {
+    /// The fields of a `KuzuValue::Struct`.
+    Struct,
+    /// The properties of a `KuzuValue::Node`.
+    Node,
+    /// The properties of a `KuzuValue::Rel`.
+    Rel,
+}
+
+impl Source {
+    /// Returns the name of the derive macro.
+    fn derive_name(self) -> &'static str {
+        match self {
+            Source::Struct => "FromKuzuStruct",
+            Source::Node => "FromKuzuNode",
+            Source::Rel => "FromKuzuRel",
+        }
+    }
+
+    /// Returns the `KuzuValue` variant holding the value.
+    fn variant(self) -> syn::Ident {
+        match self {
+            Source::Struct => format_ident!("Struct"),
+            Source::Node => format_ident!("Node"),
+            Source::Rel => format_ident!("Rel"),
+        }
+    }
+
+    /// Returns the supported field attributes, including the node or rel values a field can hold
+    /// instead of a property, and the `primary_key` of the node table usually derived alongside.
+    fn field_attrs(self) -> &'static [&'static str] {
+        match self {
+            Source::Struct => &["rename", "default", "skip", "with", "flatten"],
+            Source::Node => &[
+                "rename",
+                "default",
+                "skip",
+                "with",
+                "flatten",
+                "id",
+                "label",
+                "primary_key",
+            ],
+            Source::Rel => &[
+                "rename", "default", "skip", "with", "flatten", "label", "src", "dst",
+            ],
+        }
+    }
+
+    /// Returns the supported struct attributes besides `crate`, which are the ones of the node or
+    /// rel table usually derived alongside.
+    fn container_attrs(self) -> &'static [&'static str] {
+        match self {
+            Source::Struct => &[],
+            Source::Node => &["rename"],
+            Source::Rel => &["rename", "from", "to"],
+        }
+    }
+
+    /// Returns the expression looking up the fields or properties of `inner` by name.
+    fn fields(self) -> TokenStream2 {
+        match self {
+            Source::Struct => quote!(inner),
+            Source::Node | Source::Rel => quote!(inner.properties()),
+        }
+    }
+}
+
+fn impl_for_named_from_kuzu_struct(
+    krate: &Path,
+    fields: &FieldsNamed,
+    source: Source,
+) -> syn::Result<TokenStream2> {
+    let fields = fields
         .named
         .iter()
-        .map(|field| (field.ident.clone().unwrap(), field.ty.clone()))
-        .unzip();
+        .map(|field| {
+            let attrs = FieldAttrs::parse(field)?;
+            attrs.check_supported(source.derive_name(), source.field_attrs())?;
+            let name = field.ident.clone().unwrap();
+            let key = attrs.name(&name);
+            Ok((field, attrs, name, key))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
 
+    let keys = fields
+        .iter()
+        .filter(|(_, attrs, _, _)| !attrs.skip && !attrs.flatten && attrs.meta.is_none())
+        .map(|(_, _, _, key)| key);
     let names_vec = quote! {
-        vec![#(stringify!(#names).to_string()),*]
+        ::std::vec![#(::std::string::ToString::to_string(#keys)),*]
     };
-    quote! {
+
+    let variant = source.variant();
+    let source_fields = source.fields();
+    let inits = fields.iter().map(|(field, attrs, name, key)| {
+        let ty = &field.ty;
+        let decode_fn = match &attrs.with {
+            Some(path) => quote!(#path),
+            None => quote!(<#ty as #krate::types::decode::Decode>::decode_kuzuval),
+        };
+        let missing = match attrs.default {
+            true => quote!(::core::default::Default::default()),
+            false => quote! {
+                return ::core::result::Result::Err(#krate::error::Error::ColumnNotFound(
+                    ::std::string::ToString::to_string(#key),
+                    #names_vec,
+                ))
+            },
+        };
+
+        let value = if attrs.skip {
+            quote!(::core::default::Default::default())
+        } else if let Some(meta) = attrs.meta {
+            let meta = format_ident!("{}", meta.name());
+            quote!(::core::convert::Into::into(::std::borrow::ToOwned::to_owned(inner.#meta())))
+        } else if attrs.flatten {
+            quote! {
+                #decode_fn(#krate::types::value::KuzuValue::#variant(
+                    ::core::clone::Clone::clone(&inner)
+                ))?
+            }
+        } else {
+            quote! {
+                match #source_fields.get(#key) {
+                    ::core::option::Option::Some(value) => {
+                        #decode_fn(::core::clone::Clone::clone(value))?
+                    }
+                    ::core::option::Option::None => #missing,
+                }
+            }
+        };
+        quote!(#name: #value)
+    });
+
+    Ok(quote! {
         Self {
-            #(
-                #names: #tys::decode_kuzuval(
-                    inner
-                    .get(stringify!(#names))
-                    .ok_or(error::Error::ColumnNotFound(
-                        stringify!(#names).into(),
-                        #names_vec
-                    ))?
-                    .clone()
-                )?
-            ),*
+            #(#inits),*
         }
-    }
+    })
 }
 
-pub(super) fn from_kuzu_struct_derive(item: TokenStream) -> TokenStream {
-    let ast: DeriveInput = syn::parse(item).unwrap();
-
-    let struct_name = ast.ident;
+pub(crate) fn expand(ast: &DeriveInput, source: Source) -> syn::Result<TokenStream2> {
+    let attrs = ContainerAttrs::parse(&ast.attrs)?;
+    attrs.check_supported(source.derive_name(), source.container_attrs())?;
+    let krate = attrs.krate;
+    let struct_name = &ast.ident;
 
-    let struct_iteself = {
-        match ast.data {
-            Data::Struct(ref _s) => _s,
-            _ => unreachable!(),
-        }
+    let body = match struct_fields(ast, source.derive_name(), false)? {
+        Fields::Named(fields) => impl_for_named_from_kuzu_struct(&krate, fields, source)?,
+        _ => unreachable!("rejected by struct_fields"),
     };
 
-    let body = {
-        match struct_iteself.fields {
-            syn::Fields::Named(ref fields) => impl_for_named_from_kuzu_struct(fields),
-            _ => unreachable!(),
-        }
-    };
+    let generics = with_bounds(&ast.generics, &[quote!(#krate::types::decode::Decode)]);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let variant = source.variant();
 
-    quote! {
-        impl kuzu_rs::types::decode::Decode for #struct_name {
-              fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
-                    match value {
-                        KuzuValue::Struct(inner) => Ok(#body),
-                        ty => Err(error::Error::DecodeError(
-                            ty.name(),
-                            std::any::type_name::<Self>(),
-                        )),
+    Ok(quote! {
+        impl #impl_generics #krate::types::decode::Decode
+            for #struct_name #ty_generics #where_clause
+        {
+            fn decode_kuzuval(
+                value: #krate::types::value::KuzuValue,
+            ) -> #krate::error::Result<Self> {
+                match value {
+                    #krate::types::value::KuzuValue::#variant(inner) => {
+                        ::core::result::Result::Ok(#body)
+                    }
+                    #krate::types::value::KuzuValue::Null(_) => ::core::result::Result::Err(
+                        #krate::error::Error::UnexpectedNull(::core::any::type_name::<Self>()),
+                    ),
+                    ty => ::core::result::Result::Err(#krate::error::Error::DecodeError(
+                        ty.name(),
+                        ::core::any::type_name::<Self>(),
+                    )),
                 }
-              }
+            }
         }
-    }
-    .into()
+    })
+}
+
+pub(super) fn from_kuzu_struct_derive(item: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse_macro_input!(item);
+
+    expand(&ast, Source::Struct)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
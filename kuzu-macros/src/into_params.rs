@@ -0,0 +1,70 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::{self, spanned::Spanned, DeriveInput};
+
+use crate::attr::{ContainerAttrs, FieldAttrs};
+use crate::common::{struct_fields, with_bounds};
+
+/// The field attributes supported by `IntoParams`, including the `primary_key` of the node table
+/// usually derived alongside.
+const FIELD_ATTRS: &[&str] = &["rename", "skip", "primary_key"];
+
+/// The struct attributes supported by `IntoParams` besides `crate`, likewise for the node table.
+const CONTAINER_ATTRS: &[&str] = &["rename"];
+
+fn expand(ast: &DeriveInput) -> syn::Result<TokenStream2> {
+    let attrs = ContainerAttrs::parse(&ast.attrs)?;
+    attrs.check_supported("IntoParams", CONTAINER_ATTRS)?;
+    let krate = attrs.krate;
+    let struct_name = &ast.ident;
+
+    let params = struct_fields(ast, "IntoParams", false)?
+        .iter()
+        .map(|field| {
+            let attrs = FieldAttrs::parse(field)?;
+            attrs.check_supported("IntoParams", FIELD_ATTRS)?;
+            let name = field.ident.clone().unwrap();
+            let param = attrs.name(&name);
+            // Spanned on the type, so a field that can't be encoded, e.g. a struct, is reported on the field.
+            Ok((!attrs.skip).then(|| {
+                let value = quote_spanned! {field.ty.span()=>
+                    ::core::convert::Into::<#krate::types::value::KuzuValue>::into(
+                        ::core::clone::Clone::clone(&self.#name)
+                    )
+                };
+                quote!((#param, #value))
+            }))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let params = params.into_iter().flatten();
+
+    let generics = with_bounds(
+        &ast.generics,
+        &[
+            quote!(::core::clone::Clone),
+            quote!(::core::convert::Into<#krate::types::value::KuzuValue>),
+        ],
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #krate::prepared_statement::IntoParams
+            for #struct_name #ty_generics #where_clause
+        {
+            fn to_params(
+                &self,
+            ) -> ::std::vec::Vec<(&'static str, #krate::types::value::KuzuValue)> {
+                ::std::vec![#(#params),*]
+            }
+        }
+    })
+}
+
+pub(super) fn into_params_derive(item: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse_macro_input!(item);
+
+    expand(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
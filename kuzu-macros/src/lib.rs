@@ -1,14 +1,50 @@
+mod attr;
+mod common;
+mod from_kuzunode;
 mod from_kuzurow;
 mod from_kuzustruct;
+mod into_params;
+mod table;
+mod to_kuzustruct;
 
 use proc_macro::TokenStream;
 
-#[proc_macro_derive(FromKuzuStruct)]
+#[proc_macro_derive(FromKuzuStruct, attributes(kuzu))]
 pub fn kuzu_struct_derive(item: TokenStream) -> TokenStream {
     from_kuzustruct::from_kuzu_struct_derive(item)
 }
 
-#[proc_macro_derive(FromKuzuRow)]
+#[proc_macro_derive(FromKuzuRow, attributes(kuzu))]
 pub fn kuzu_row_derive(item: TokenStream) -> TokenStream {
     from_kuzurow::from_kuzu_row_derive(item)
 }
+
+#[proc_macro_derive(FromKuzuNode, attributes(kuzu))]
+pub fn kuzu_node_derive(item: TokenStream) -> TokenStream {
+    from_kuzunode::from_kuzu_node_derive(item)
+}
+
+#[proc_macro_derive(FromKuzuRel, attributes(kuzu))]
+pub fn kuzu_rel_derive(item: TokenStream) -> TokenStream {
+    from_kuzunode::from_kuzu_rel_derive(item)
+}
+
+#[proc_macro_derive(ToKuzuStruct, attributes(kuzu))]
+pub fn to_kuzu_struct_derive(item: TokenStream) -> TokenStream {
+    to_kuzustruct::to_kuzu_struct_derive(item)
+}
+
+#[proc_macro_derive(IntoParams, attributes(kuzu))]
+pub fn into_params_derive(item: TokenStream) -> TokenStream {
+    into_params::into_params_derive(item)
+}
+
+#[proc_macro_derive(KuzuNodeTable, attributes(kuzu))]
+pub fn kuzu_node_table_derive(item: TokenStream) -> TokenStream {
+    table::kuzu_node_table_derive(item)
+}
+
+#[proc_macro_derive(KuzuRelTable, attributes(kuzu))]
+pub fn kuzu_rel_table_derive(item: TokenStream) -> TokenStream {
+    table::kuzu_rel_table_derive(item)
+}
@@ -0,0 +1,201 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{self, DeriveInput};
+
+use crate::attr::{ContainerAttrs, FieldAttrs};
+use crate::common::{struct_fields, with_bounds};
+
+/// The kind of table a macro is derived for.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Node,
+    Rel,
+}
+
+impl Kind {
+    /// Returns the name of the derive macro.
+    fn derive(self) -> &'static str {
+        match self {
+            Kind::Node => "KuzuNodeTable",
+            Kind::Rel => "KuzuRelTable",
+        }
+    }
+
+    /// Returns the supported field attributes, including the ones of `FromKuzuNode` and `FromKuzuRel`,
+    /// which are usually derived alongside and don't change the table.
+    fn field_attrs(self) -> &'static [&'static str] {
+        match self {
+            Kind::Node => &[
+                "rename",
+                "skip",
+                "primary_key",
+                "default",
+                "with",
+                "id",
+                "label",
+            ],
+            Kind::Rel => &["rename", "skip", "default", "with", "label", "src", "dst"],
+        }
+    }
+
+    /// Returns the supported struct attributes besides `crate`.
+    fn container_attrs(self) -> &'static [&'static str] {
+        match self {
+            Kind::Node => &["rename"],
+            Kind::Rel => &["rename", "from", "to"],
+        }
+    }
+}
+
+fn expand(ast: &DeriveInput, kind: Kind) -> syn::Result<TokenStream2> {
+    let attrs = ContainerAttrs::parse(&ast.attrs)?;
+    let derive = kind.derive();
+    attrs.check_supported(derive, kind.container_attrs())?;
+    let ContainerAttrs {
+        krate,
+        rename,
+        from,
+        to,
+        ..
+    } = attrs;
+    let struct_name = &ast.ident;
+    let table_name = rename.unwrap_or_else(|| struct_name.to_string());
+
+    let mut primary_key = None;
+    let mut properties = vec![];
+    for field in struct_fields(ast, derive, false)? {
+        let attrs = FieldAttrs::parse(field)?;
+        attrs.check_supported(derive, kind.field_attrs())?;
+        if attrs.skip || attrs.meta.is_some() {
+            continue;
+        }
+
+        let name = attrs.name(field.ident.as_ref().unwrap());
+        if attrs.primary_key {
+            if primary_key.is_some() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "a node table can only have one `primary_key`",
+                ));
+            }
+            primary_key = Some(name.clone());
+        }
+
+        let ty = &field.ty;
+        properties.push(quote! {
+            #krate::catalog::Property {
+                name: ::std::borrow::ToOwned::to_owned(#name),
+                logical_type: <#ty as #krate::types::logical_type::KuzuType>::logical_type(),
+            }
+        });
+    }
+
+    let property_definitions = quote! {
+        <Self as #krate::schema::KuzuTable>::properties()
+            .iter()
+            .map(|property| {
+                ::std::format!(
+                    "{} {}",
+                    #krate::schema::quote_identifier(&property.name),
+                    property.logical_type,
+                )
+            })
+    };
+    let (kind, create_statement, schema) = match kind {
+        Kind::Node => {
+            let primary_key = primary_key.ok_or_else(|| {
+                syn::Error::new_spanned(
+                    struct_name,
+                    "KuzuNodeTable requires a field marked with `#[kuzu(primary_key)]`",
+                )
+            })?;
+            let create_statement = quote! {
+                let properties: ::std::vec::Vec<::std::string::String> = #property_definitions.collect();
+                ::std::format!(
+                    "CREATE NODE TABLE {}({}, PRIMARY KEY ({}));",
+                    #krate::schema::quote_identifier(<Self as #krate::schema::KuzuTable>::NAME),
+                    properties.join(", "),
+                    #krate::schema::quote_identifier(#primary_key),
+                )
+            };
+            let schema = quote! {
+                fn primary_key() -> ::std::option::Option<&'static str> {
+                    ::std::option::Option::Some(#primary_key)
+                }
+            };
+            (quote!(Node), create_statement, schema)
+        }
+        Kind::Rel => {
+            let (from, to) = from.zip(to).ok_or_else(|| {
+                syn::Error::new_spanned(
+                    struct_name,
+                    "KuzuRelTable requires `#[kuzu(from = ..., to = ...)]`",
+                )
+            })?;
+            let create_statement = quote! {
+                let mut parts: ::std::vec::Vec<::std::string::String> = ::std::vec![
+                    ::std::format!(
+                        "FROM {} TO {}",
+                        #krate::schema::quote_identifier(<#from as #krate::schema::KuzuTable>::NAME),
+                        #krate::schema::quote_identifier(<#to as #krate::schema::KuzuTable>::NAME),
+                    )
+                ];
+                parts.extend(#property_definitions);
+                ::std::format!(
+                    "CREATE REL TABLE {}({});",
+                    #krate::schema::quote_identifier(<Self as #krate::schema::KuzuTable>::NAME),
+                    parts.join(", "),
+                )
+            };
+            let schema = quote! {
+                fn endpoints() -> ::std::option::Option<(&'static str, &'static str)> {
+                    ::std::option::Option::Some((
+                        <#from as #krate::schema::KuzuTable>::NAME,
+                        <#to as #krate::schema::KuzuTable>::NAME,
+                    ))
+                }
+            };
+            (quote!(Rel), create_statement, schema)
+        }
+    };
+
+    let generics = with_bounds(
+        &ast.generics,
+        &[quote!(#krate::types::logical_type::KuzuType)],
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #krate::schema::KuzuTable for #struct_name #ty_generics #where_clause {
+            const NAME: &'static str = #table_name;
+            const KIND: #krate::schema::TableKind = #krate::schema::TableKind::#kind;
+
+            fn create_statement() -> ::std::string::String {
+                #create_statement
+            }
+
+            fn properties() -> ::std::vec::Vec<#krate::catalog::Property> {
+                ::std::vec![#(#properties),*]
+            }
+
+            #schema
+        }
+    })
+}
+
+pub(super) fn kuzu_node_table_derive(item: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse_macro_input!(item);
+
+    expand(&ast, Kind::Node)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+pub(super) fn kuzu_rel_table_derive(item: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse_macro_input!(item);
+
+    expand(&ast, Kind::Rel)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
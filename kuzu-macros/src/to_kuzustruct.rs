@@ -0,0 +1,67 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{self, DeriveInput};
+
+use crate::attr::{ContainerAttrs, FieldAttrs};
+use crate::common::{struct_fields, with_bounds};
+
+/// The field attributes supported by `ToKuzuStruct`.
+const FIELD_ATTRS: &[&str] = &["rename", "skip"];
+
+fn expand(ast: &DeriveInput) -> syn::Result<TokenStream2> {
+    let attrs = ContainerAttrs::parse(&ast.attrs)?;
+    attrs.check_supported("ToKuzuStruct", &[])?;
+    let krate = attrs.krate;
+    let struct_name = &ast.ident;
+
+    let fields = struct_fields(ast, "ToKuzuStruct", false)?
+        .iter()
+        .map(|field| {
+            let attrs = FieldAttrs::parse(field)?;
+            attrs.check_supported("ToKuzuStruct", FIELD_ATTRS)?;
+            let name = field.ident.clone().unwrap();
+            let key = attrs.name(&name);
+            Ok((!attrs.skip).then(|| {
+                quote! {
+                    (
+                        ::std::string::ToString::to_string(#key),
+                        ::core::convert::Into::<#krate::types::value::KuzuValue>::into(
+                            ::core::clone::Clone::clone(&self.#name)
+                        ),
+                    )
+                }
+            }))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let fields = fields.into_iter().flatten();
+
+    let generics = with_bounds(
+        &ast.generics,
+        &[
+            quote!(::core::clone::Clone),
+            quote!(::core::convert::Into<#krate::types::value::KuzuValue>),
+        ],
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #krate::types::encode::ToKuzuStruct
+            for #struct_name #ty_generics #where_clause
+        {
+            fn to_kuzu_struct(&self) -> #krate::types::value::Struct {
+                ::core::iter::Iterator::collect(::core::iter::IntoIterator::into_iter([
+                    #(#fields),*
+                ]))
+            }
+        }
+    })
+}
+
+pub(super) fn to_kuzu_struct_derive(item: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse_macro_input!(item);
+
+    expand(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
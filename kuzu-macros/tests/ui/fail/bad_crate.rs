@@ -0,0 +1,7 @@
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+#[kuzu(crate = 1)]
+struct Row {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+struct Row {
+    #[kuzu(skip, default)]
+    name: String,
+}
+
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+struct Tuple(#[kuzu(rename = "a")] String);
+
+#[derive(kuzu_rs::macros::IntoParams)]
+struct Params {
+    #[kuzu(default)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+#[kuzu(rename = "ROW")]
+struct Row {
+    name: String,
+}
+
+#[derive(kuzu_rs::macros::FromKuzuStruct)]
+#[kuzu(rename = "PERSON")]
+struct Person {
+    name: String,
+}
+
+#[derive(kuzu_rs::macros::IntoParams)]
+#[kuzu(from = Person, to = Person)]
+struct Params {
+    name: String,
+}
+
+#[derive(Clone, kuzu_rs::macros::ToKuzuStruct)]
+#[kuzu(rename = "ADDRESS")]
+struct Address {
+    city: String,
+}
+
+#[derive(kuzu_rs::macros::KuzuNodeTable)]
+#[kuzu(from = Person, to = Person)]
+struct User {
+    #[kuzu(primary_key)]
+    name: String,
+}
+
+fn main() {}
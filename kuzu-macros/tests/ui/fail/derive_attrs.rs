@@ -0,0 +1,27 @@
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+struct Row {
+    #[kuzu(primary_key)]
+    name: String,
+}
+
+#[derive(kuzu_rs::macros::FromKuzuStruct)]
+struct Person {
+    #[kuzu(rename = "NAME", primary_key)]
+    name: String,
+}
+
+#[derive(kuzu_rs::macros::KuzuNodeTable)]
+struct User {
+    #[kuzu(primary_key)]
+    name: String,
+    #[kuzu(flatten)]
+    person: Person,
+}
+
+#[derive(Clone, kuzu_rs::macros::ToKuzuStruct)]
+struct Address {
+    #[kuzu(with = decode_city)]
+    city: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+enum Row {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#[derive(kuzu_rs::macros::FromKuzuNode)]
+struct User {
+    #[kuzu(src)]
+    src: kuzu_rs::types::value::InternalId,
+}
+
+#[derive(kuzu_rs::macros::FromKuzuRel)]
+struct Follows {
+    #[kuzu(id, rename = "id")]
+    id: kuzu_rs::types::value::InternalId,
+}
+
+#[derive(kuzu_rs::macros::FromKuzuStruct)]
+struct Person {
+    #[kuzu(label)]
+    label: String,
+}
+
+fn main() {}
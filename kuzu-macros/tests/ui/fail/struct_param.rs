@@ -0,0 +1,13 @@
+#[derive(Clone, kuzu_rs::macros::ToKuzuStruct)]
+struct Address {
+    city: String,
+}
+
+// Kuzu can't bind struct values, so a struct can't be a parameter.
+#[derive(kuzu_rs::macros::IntoParams)]
+struct Params {
+    name: String,
+    address: Address,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+#[derive(kuzu_rs::macros::KuzuNodeTable)]
+struct User {
+    name: String,
+}
+
+#[derive(kuzu_rs::macros::KuzuNodeTable)]
+struct City {
+    #[kuzu(primary_key)]
+    name: String,
+    #[kuzu(primary_key)]
+    code: String,
+}
+
+#[derive(kuzu_rs::macros::KuzuRelTable)]
+#[kuzu(from = City)]
+struct LivesIn {
+    since: i64,
+}
+
+#[derive(kuzu_rs::macros::KuzuRelTable)]
+#[kuzu(from = City, to = City)]
+struct Road {
+    #[kuzu(primary_key)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(kuzu_rs::macros::FromKuzuStruct)]
+struct Person;
+
+#[derive(kuzu_rs::macros::IntoParams)]
+struct Params(i64);
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+struct Row {
+    #[kuzu(renam = "a.name")]
+    name: String,
+}
+
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+#[kuzu(krate = "kuzu_rs")]
+struct Other {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+mod reexport {
+    pub use kuzu_rs::*;
+}
+
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+#[kuzu(crate = "crate::reexport")]
+struct Row(i64, String);
+
+#[derive(kuzu_rs::macros::FromKuzuStruct)]
+#[kuzu(crate = "crate::reexport")]
+struct Person {
+    #[kuzu(rename = "FIRST")]
+    first: String,
+}
+
+fn main() {}
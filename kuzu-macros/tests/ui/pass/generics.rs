@@ -0,0 +1,22 @@
+use std::marker::PhantomData;
+
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+struct Row<'a, T> {
+    value: T,
+    #[kuzu(skip)]
+    marker: PhantomData<&'a ()>,
+}
+
+#[derive(kuzu_rs::macros::FromKuzuStruct)]
+struct Pair<T> {
+    first: T,
+    second: Option<T>,
+}
+
+#[derive(Clone, kuzu_rs::macros::IntoParams, kuzu_rs::macros::ToKuzuStruct)]
+struct Params<T> {
+    #[kuzu(rename = "id")]
+    value: T,
+}
+
+fn main() {}
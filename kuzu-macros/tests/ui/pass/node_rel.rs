@@ -0,0 +1,30 @@
+use kuzu_rs::types::value::InternalId;
+
+#[derive(kuzu_rs::macros::FromKuzuNode)]
+struct User {
+    #[kuzu(id)]
+    id: Option<InternalId>,
+    #[kuzu(label)]
+    label: String,
+    #[kuzu(rename = "fullName")]
+    name: String,
+    #[kuzu(default)]
+    age: Option<i64>,
+}
+
+#[derive(kuzu_rs::macros::FromKuzuRel)]
+struct Follows {
+    #[kuzu(src)]
+    src: InternalId,
+    #[kuzu(dst)]
+    dst: InternalId,
+    since: i64,
+}
+
+#[derive(kuzu_rs::macros::FromKuzuRow)]
+struct Row {
+    user: User,
+    follows: Follows,
+}
+
+fn main() {}
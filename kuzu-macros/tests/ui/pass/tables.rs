@@ -0,0 +1,84 @@
+use kuzu_rs::catalog::Property;
+use kuzu_rs::schema::KuzuTable;
+use kuzu_rs::types::logical_type::LogicalType;
+use kuzu_rs::types::value::InternalId;
+
+#[derive(kuzu_rs::macros::KuzuNodeTable, kuzu_rs::macros::FromKuzuNode)]
+struct User {
+    #[kuzu(id)]
+    id: Option<InternalId>,
+    #[kuzu(primary_key)]
+    name: String,
+    #[kuzu(rename = "yearsOld", default)]
+    age: Option<i64>,
+    scores: Vec<f64>,
+    #[kuzu(skip)]
+    note: String,
+}
+
+#[derive(kuzu_rs::macros::KuzuNodeTable)]
+#[kuzu(rename = "Town")]
+struct City {
+    #[kuzu(primary_key)]
+    name: String,
+    location: [f32; 2],
+}
+
+#[derive(kuzu_rs::macros::KuzuRelTable, kuzu_rs::macros::FromKuzuRel)]
+#[kuzu(from = User, to = City)]
+struct LivesIn {
+    #[kuzu(src)]
+    src: InternalId,
+    since: i64,
+}
+
+#[derive(kuzu_rs::macros::KuzuRelTable)]
+#[kuzu(from = User, to = User)]
+struct Follows {}
+
+fn main() {
+    assert_eq!(
+        User::create_statement(),
+        "CREATE NODE TABLE `User`(`name` STRING, `yearsOld` INT64, `scores` DOUBLE[], PRIMARY KEY (`name`));"
+    );
+    assert_eq!(
+        City::create_statement(),
+        "CREATE NODE TABLE `Town`(`name` STRING, `location` FLOAT[2], PRIMARY KEY (`name`));"
+    );
+    assert_eq!(
+        LivesIn::create_statement(),
+        "CREATE REL TABLE `LivesIn`(FROM `User` TO `Town`, `since` INT64);"
+    );
+    assert_eq!(
+        Follows::create_statement(),
+        "CREATE REL TABLE `Follows`(FROM `User` TO `User`);"
+    );
+
+    assert_eq!(
+        User::properties(),
+        [
+            property("name", LogicalType::String),
+            property("yearsOld", LogicalType::Int64),
+            property(
+                "scores",
+                LogicalType::VarList(Box::new(LogicalType::Double))
+            ),
+        ]
+    );
+    assert_eq!(User::primary_key(), Some("name"));
+    assert_eq!(User::endpoints(), None);
+    assert_eq!(
+        LivesIn::properties(),
+        [property("since", LogicalType::Int64)]
+    );
+    assert_eq!(LivesIn::primary_key(), None);
+    assert_eq!(LivesIn::endpoints(), Some(("User", "Town")));
+    assert!(Follows::properties().is_empty());
+}
+
+fn property(name: &str, logical_type: LogicalType) -> Property {
+    Property {
+        name: name.to_owned(),
+        logical_type,
+    }
+}
@@ -0,0 +1,43 @@
+use kuzu_rs::{
+    connection::Connection, database::Database, error, macros::FromKuzuRow, types::value::KuzuValue,
+};
+
+fn decode_uppercase(value: KuzuValue) -> error::Result<String> {
+    match value {
+        KuzuValue::String(name) => Ok(name.to_uppercase()),
+        value => Err(error::Error::DecodeError(value.name(), "String")),
+    }
+}
+
+#[derive(FromKuzuRow)]
+struct Name {
+    #[kuzu(with = decode_uppercase)]
+    name: String,
+}
+
+#[derive(FromKuzuRow)]
+struct Person {
+    #[kuzu(flatten)]
+    name: Name,
+    age: i64,
+}
+
+fn main() {
+    let dir = std::env::temp_dir().join(format!("kuzu-macros-with-flatten-{}", std::process::id()));
+    {
+        let db = Database::builder(dir.to_str().unwrap()).build().unwrap();
+        let conn = Connection::new(&db).unwrap();
+        let people = conn
+            .query("RETURN 'alice' AS name, 35 AS age;")
+            .unwrap()
+            .iter::<Person>()
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(people.len(), 1);
+        assert_eq!(people[0].name.name, "ALICE");
+        assert_eq!(people[0].age, 35);
+    }
+    std::fs::remove_dir_all(dir).unwrap();
+}
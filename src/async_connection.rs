@@ -0,0 +1,357 @@
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    connection::{Connection, InterruptHandle},
+    database::Database,
+    error,
+    query_result::QueryResult,
+    types::{row::Row, value::KuzuValue},
+};
+
+/// The number of decoded rows buffered ahead of the consumer of a `RowStream`.
+const ROW_BUFFER_SIZE: usize = 64;
+
+/// An async facade over a `Connection`.
+///
+/// Queries run on the tokio blocking pool, so the methods must be called from within a tokio runtime.
+/// Queries on the same `AsyncConnection` run one at a time; open one per task to run them concurrently.
+/// A query holds the connection until its `RowStream` is exhausted or dropped, so the queries of the
+/// clones wait for it.
+/// Dropping a query future before it completes interrupts the query, or keeps it from running if it is
+/// still waiting for a query of another clone.
+#[derive(Clone)]
+pub struct AsyncConnection {
+    /// The wrapped connection, locked by the blocking task running a query.
+    conn: Arc<Mutex<Connection>>,
+    /// The interrupt handle of the wrapped connection.
+    interrupt: InterruptHandle,
+}
+
+impl From<Connection> for AsyncConnection {
+    fn from(conn: Connection) -> Self {
+        Self {
+            interrupt: conn.interrupt_handle(),
+            conn: Arc::new(Mutex::new(conn)),
+        }
+    }
+}
+
+impl AsyncConnection {
+    /// Creates a new connection to the specified database.
+    pub fn new(database: &Database) -> error::Result<Self> {
+        Connection::new(database).map(Self::from)
+    }
+
+    /// Returns a handle that can interrupt the queries running on this connection.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.interrupt.clone()
+    }
+
+    /// Executes a query and returns a stream over its rows.
+    /// Returns an error if there is an issue executing the query or retrieving the query result.
+    pub async fn query<R, S>(&self, query: S) -> error::Result<RowStream<R>>
+    where
+        R: TryFrom<Row> + Send + 'static,
+        error::Error: From<R::Error>,
+        S: Into<String>,
+    {
+        let query = query.into();
+        self.run(move |conn| conn.query(query)).await
+    }
+
+    /// Prepares a query, binds the given parameters and executes it, returning a stream over its rows.
+    /// Returns an error if there is an issue preparing or executing the statement.
+    pub async fn execute<R, S>(
+        &self,
+        query: S,
+        params: Vec<(String, KuzuValue)>,
+    ) -> error::Result<RowStream<R>>
+    where
+        R: TryFrom<Row> + Send + 'static,
+        error::Error: From<R::Error>,
+        S: Into<String>,
+    {
+        let query = query.into();
+        self.run(move |conn| execute_with(conn, &query, params))
+            .await
+    }
+
+    /// Runs `query` on the blocking pool and streams the decoded rows of its result.
+    async fn run<R, F>(&self, query: F) -> error::Result<RowStream<R>>
+    where
+        R: TryFrom<Row> + Send + 'static,
+        error::Error: From<R::Error>,
+        F: FnOnce(&mut Connection) -> error::Result<QueryResult> + Send + 'static,
+    {
+        let conn = Arc::clone(&self.conn);
+        let job = Arc::new(Mutex::new(JobState::Queued));
+        let (result_tx, result_rx) = oneshot::channel();
+        let (rows_tx, rows_rx) = mpsc::channel(ROW_BUFFER_SIZE);
+
+        let task = tokio::task::spawn_blocking({
+            let job = Arc::clone(&job);
+            move || {
+                // The lock is held until the rows are streamed, so no other query runs on the
+                // connection, or is hit by an interrupt meant for this one, in the meantime.
+                let mut conn = conn.lock().unwrap_or_else(PoisonError::into_inner);
+                if !JobState::start(&job) {
+                    return;
+                }
+                let res = query(&mut conn);
+                JobState::finish(&job);
+                let rows = res.and_then(|res| res.iter::<R>());
+
+                let rows = match rows {
+                    Ok(rows) => {
+                        let _ = result_tx.send(Ok(()));
+                        rows
+                    }
+                    Err(e) => {
+                        let _ = result_tx.send(Err(e));
+                        return;
+                    }
+                };
+
+                for row in rows {
+                    // The receiver is gone once the stream has been dropped.
+                    if rows_tx.blocking_send(row).is_err() {
+                        break;
+                    }
+                }
+                drop(conn);
+            }
+        });
+
+        let guard = CancelOnDrop(Some((job, self.interrupt.clone())));
+        let res = result_rx.await;
+        guard.disarm();
+
+        match res {
+            Ok(res) => res?,
+            // The task only drops the sender without a result when it failed.
+            Err(_) => {
+                let e = task.await.err().map_or_else(
+                    || "the task ended without a result".to_owned(),
+                    |e| e.to_string(),
+                );
+                return Err(error::Error::BlockingTaskFailed(e));
+            }
+        }
+
+        Ok(RowStream { rows: rows_rx })
+    }
+}
+
+/// The progress of a query submitted to the blocking pool.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum JobState {
+    /// Waiting for the connection, which runs a query of another clone.
+    Queued,
+    /// Running on the connection.
+    Running,
+    /// The query returned, its rows may still be streamed.
+    Done,
+    /// The query future was dropped before the query started, so it won't run.
+    Cancelled,
+}
+
+impl JobState {
+    fn lock(job: &Mutex<Self>) -> MutexGuard<'_, Self> {
+        job.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Marks the job as running, returns `false` if it was cancelled while queued.
+    fn start(job: &Mutex<Self>) -> bool {
+        let mut state = Self::lock(job);
+        if *state == Self::Cancelled {
+            return false;
+        }
+        *state = Self::Running;
+        true
+    }
+
+    /// Marks the query of the job as returned.
+    fn finish(job: &Mutex<Self>) {
+        *Self::lock(job) = Self::Done;
+    }
+}
+
+/// Cancels a queued job or interrupts its running query when dropped, unless disarmed.
+///
+/// A job that hasn't started doesn't interrupt the connection, which may be running the query of another clone.
+struct CancelOnDrop(Option<(Arc<Mutex<JobState>>, InterruptHandle)>);
+
+impl CancelOnDrop {
+    fn disarm(mut self) {
+        self.0.take();
+    }
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if let Some((job, handle)) = self.0.take() {
+            let mut state = JobState::lock(&job);
+            match *state {
+                JobState::Queued => *state = JobState::Cancelled,
+                // Interrupting under the lock keeps the job from being marked as done in between.
+                JobState::Running => handle.interrupt(),
+                JobState::Done | JobState::Cancelled => {}
+            }
+        }
+    }
+}
+
+/// A stream over the rows of a query result, decoded on the blocking pool.
+///
+/// The connection of the query stays locked until the stream is exhausted or dropped.
+pub struct RowStream<R> {
+    rows: mpsc::Receiver<error::Result<R>>,
+}
+
+impl<R> Stream for RowStream<R> {
+    type Item = error::Result<R>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rows.poll_recv(cx)
+    }
+}
+
+/// Prepares a query, binds the given parameters and executes it.
+fn execute_with(
+    conn: &mut Connection,
+    query: &str,
+    params: Vec<(String, KuzuValue)>,
+) -> error::Result<QueryResult> {
+    let mut stmt = conn.prepare(query)?;
+    for (key, val) in params {
+        stmt.bind(key.as_str(), val)?;
+    }
+    stmt.execute()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{future::poll_fn, pin::Pin, time::Duration};
+
+    use futures_core::Stream;
+
+    use super::{AsyncConnection, RowStream};
+    use crate::{error, test_utils::test_database, types::row::Row};
+
+    /// A query running long enough to be interrupted.
+    const LONG_QUERY: &str = "UNWIND range(1, 1000000000) AS x RETURN sum(x);";
+
+    async fn next<R>(stream: &mut RowStream<R>) -> Option<error::Result<R>> {
+        poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx)).await
+    }
+
+    fn assert_send<T: Send>(_: &T) {}
+    fn assert_send_sync<T: Send + Sync + Clone>() {}
+
+    #[test]
+    fn test_async_connection_is_send_sync() {
+        assert_send_sync::<AsyncConnection>();
+    }
+
+    #[allow(dead_code)]
+    fn query_future_is_send(conn: &AsyncConnection) {
+        let query = conn.query::<Row, _>("RETURN 1;");
+        assert_send(&query);
+        let execute = conn.execute::<(i64,), _>("RETURN $a;", vec![]);
+        assert_send(&execute);
+    }
+
+    #[allow(dead_code)]
+    fn row_stream_is_send(stream: RowStream<Row>) {
+        assert_send(&stream);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_dropped_queued_query_does_not_interrupt_clone() {
+        let (_dir, db) = test_database();
+        let conn = AsyncConnection::new(&db).unwrap();
+
+        let running = tokio::spawn({
+            let conn = conn.clone();
+            async move {
+                conn.query::<(i64,), _>("UNWIND range(1, 10000000) AS x RETURN sum(x);")
+                    .await
+                    .map(drop)
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        // The query of the clone waits for the running one, and is dropped while it does.
+        let queued = conn.query::<Row, _>("RETURN 1;");
+        let _ = tokio::time::timeout(Duration::from_millis(10), queued).await;
+
+        running.await.unwrap().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_query_streams_rows() {
+        let (_dir, db) = test_database();
+        let conn = AsyncConnection::new(&db).unwrap();
+
+        // More rows than are buffered ahead of the stream.
+        let mut stream = conn
+            .query::<(i64,), _>("UNWIND range(1, 200) AS x RETURN x;")
+            .await
+            .unwrap();
+        let mut values = vec![];
+        while let Some(row) = next(&mut stream).await {
+            values.push(row.unwrap().0);
+        }
+        assert_eq!(values, (1..=200).collect::<Vec<_>>());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_stream_holds_connection() {
+        let (_dir, db) = test_database();
+        let conn = AsyncConnection::new(&db).unwrap();
+
+        let mut stream = conn
+            .query::<(i64,), _>("UNWIND range(1, 200) AS x RETURN x;")
+            .await
+            .unwrap();
+        assert_eq!(next(&mut stream).await.unwrap().unwrap(), (1,));
+
+        // The clone waits for the rows of the first query to be streamed.
+        let clone = conn.clone();
+        let waiting = tokio::time::timeout(
+            Duration::from_millis(50),
+            clone.query::<Row, _>("RETURN 1;"),
+        );
+        assert!(waiting.await.is_err());
+
+        drop(stream);
+        let mut stream = clone.query::<(i64,), _>("RETURN 1;").await.unwrap();
+        assert_eq!(next(&mut stream).await.unwrap().unwrap(), (1,));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_dropped_running_query_is_interrupted() {
+        let (_dir, db) = test_database();
+        let conn = AsyncConnection::new(&db).unwrap();
+
+        let running = conn.query::<(i64,), _>(LONG_QUERY);
+        assert!(tokio::time::timeout(Duration::from_millis(100), running)
+            .await
+            .is_err());
+
+        // The next query only gets the connection once the interrupted one returned.
+        let next_query = conn.query::<(i64,), _>("RETURN 1;");
+        let mut stream = tokio::time::timeout(Duration::from_secs(5), next_query)
+            .await
+            .expect("the dropped query was not interrupted")
+            .unwrap();
+        assert_eq!(next(&mut stream).await.unwrap().unwrap(), (1,));
+    }
+}
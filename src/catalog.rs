@@ -0,0 +1,329 @@
+use crate::{
+    connection::Connection, error, ffi, helper::convert_inner_to_owned_string, into_cstr,
+    types::logical_type::LogicalType,
+};
+
+/// Represents the schema of a database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Catalog {
+    /// The node tables of the database.
+    pub node_tables: Vec<NodeTable>,
+    /// The rel tables of the database.
+    pub rel_tables: Vec<RelTable>,
+}
+
+impl Catalog {
+    /// Returns the node table with the given name, if it exists.
+    pub fn node_table<S: AsRef<str>>(&self, name: S) -> Option<&NodeTable> {
+        self.node_tables.iter().find(|t| t.name == name.as_ref())
+    }
+
+    /// Returns the rel table with the given name, if it exists.
+    pub fn rel_table<S: AsRef<str>>(&self, name: S) -> Option<&RelTable> {
+        self.rel_tables.iter().find(|t| t.name == name.as_ref())
+    }
+}
+
+/// Represents a property of a node or rel table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    /// The name of the property.
+    pub name: String,
+    /// The logical type of the property.
+    pub logical_type: LogicalType,
+}
+
+/// Represents a node table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeTable {
+    /// The name of the table.
+    pub name: String,
+    /// The properties of the table, in declaration order.
+    pub properties: Vec<Property>,
+    /// The name of the primary key property.
+    pub primary_key: String,
+}
+
+/// Represents a rel table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelTable {
+    /// The name of the table.
+    pub name: String,
+    /// The name of the node table the rels start from.
+    pub src: String,
+    /// The name of the node table the rels end at.
+    pub dst: String,
+    /// The properties of the table, in declaration order.
+    pub properties: Vec<Property>,
+}
+
+/// Suffix Kuzu appends to the type of the primary key property.
+const PRIMARY_KEY_SUFFIX: &str = "(PRIMARY KEY)";
+
+/// Parses a table name listing such as `"Node tables: \n\tUser\n\tCity\n"`.
+fn parse_table_names(s: &str) -> Vec<String> {
+    s.lines()
+        .skip(1)
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Parses a property line such as `"\tage INT64"`, returning whether it is the primary key.
+fn parse_property(line: &str) -> error::Result<(Property, bool)> {
+    let line = line.trim();
+    let (line, is_primary_key) = match line.strip_suffix(PRIMARY_KEY_SUFFIX) {
+        Some(line) => (line, true),
+        None => (line, false),
+    };
+
+    let (name, logical_type) = line
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| error::Error::CatalogParseError(line.to_owned()))?;
+
+    let property = Property {
+        name: name.to_owned(),
+        logical_type: logical_type.parse()?,
+    };
+    Ok((property, is_primary_key))
+}
+
+/// Parses the property listing of a node table, e.g. `"User properties: \n\tname STRING(PRIMARY KEY)\n"`.
+fn parse_node_table(name: String, s: &str) -> error::Result<NodeTable> {
+    let mut primary_key = None;
+    let properties = s
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (property, is_primary_key) = parse_property(line)?;
+            if is_primary_key {
+                primary_key = Some(property.name.clone());
+            }
+            Ok(property)
+        })
+        .collect::<error::Result<_>>()?;
+
+    let primary_key = primary_key.ok_or_else(|| {
+        error::Error::CatalogParseError(format!("node table {name} has no primary key"))
+    })?;
+
+    Ok(NodeTable {
+        name,
+        properties,
+        primary_key,
+    })
+}
+
+/// Parses the property listing of a rel table, e.g.
+/// `"Follows src node: User\nFollows dst node: User\nFollows properties: \n\tsince INT64\n"`.
+fn parse_rel_table(name: String, s: &str) -> error::Result<RelTable> {
+    let src_prefix = format!("{name} src node:");
+    let dst_prefix = format!("{name} dst node:");
+    let properties_prefix = format!("{name} properties:");
+
+    let (mut src, mut dst) = (None, None);
+    let mut properties = vec![];
+    let mut in_properties = false;
+
+    for line in s.lines().filter(|line| !line.trim().is_empty()) {
+        if let Some(table) = line.strip_prefix(&src_prefix) {
+            src = Some(table.trim().to_owned());
+        } else if let Some(table) = line.strip_prefix(&dst_prefix) {
+            dst = Some(table.trim().to_owned());
+        } else if line.starts_with(&properties_prefix) {
+            in_properties = true;
+        } else if in_properties {
+            properties.push(parse_property(line)?.0);
+        } else {
+            return Err(error::Error::CatalogParseError(line.to_owned()));
+        }
+    }
+
+    let missing = |endpoint| {
+        error::Error::CatalogParseError(format!("rel table {name} has no {endpoint} node"))
+    };
+    Ok(RelTable {
+        src: src.ok_or_else(|| missing("src"))?,
+        dst: dst.ok_or_else(|| missing("dst"))?,
+        name,
+        properties,
+    })
+}
+
+impl Connection {
+    /// Returns the node and rel tables of the database.
+    /// Returns an error if the schema description returned by Kuzu cannot be parsed.
+    pub fn catalog(&self) -> error::Result<Catalog> {
+        let node_tables = self
+            .node_table_names()?
+            .into_iter()
+            .map(|name| {
+                let cname = into_cstr!(name.as_str())?;
+                let properties = convert_inner_to_owned_string(unsafe {
+                    ffi::kuzu_connection_get_node_property_names(self.to_inner(), cname.as_ptr())
+                })?;
+                parse_node_table(name, &properties)
+            })
+            .collect::<error::Result<_>>()?;
+
+        let rel_tables = self
+            .rel_table_names()?
+            .into_iter()
+            .map(|name| {
+                let cname = into_cstr!(name.as_str())?;
+                let properties = convert_inner_to_owned_string(unsafe {
+                    ffi::kuzu_connection_get_rel_property_names(self.to_inner(), cname.as_ptr())
+                })?;
+                parse_rel_table(name, &properties)
+            })
+            .collect::<error::Result<_>>()?;
+
+        Ok(Catalog {
+            node_tables,
+            rel_tables,
+        })
+    }
+
+    /// Returns the names of the node tables of the database.
+    pub fn node_table_names(&self) -> error::Result<Vec<String>> {
+        let names = convert_inner_to_owned_string(unsafe {
+            ffi::kuzu_connection_get_node_table_names(self.to_inner())
+        })?;
+        Ok(parse_table_names(&names))
+    }
+
+    /// Returns the names of the rel tables of the database.
+    pub fn rel_table_names(&self) -> error::Result<Vec<String>> {
+        let names = convert_inner_to_owned_string(unsafe {
+            ffi::kuzu_connection_get_rel_table_names(self.to_inner())
+        })?;
+        Ok(parse_table_names(&names))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse_node_table, parse_rel_table, parse_table_names, NodeTable, Property, RelTable,
+    };
+    use crate::{
+        connection::Connection, test_utils::test_database, types::logical_type::LogicalType,
+    };
+
+    fn property(name: &str, logical_type: LogicalType) -> Property {
+        Property {
+            name: name.to_owned(),
+            logical_type,
+        }
+    }
+
+    #[test]
+    fn test_parse_table_names() {
+        assert_eq!(
+            parse_table_names("Node tables: \n\tUser\n\tCity\n"),
+            vec!["User".to_owned(), "City".to_owned()]
+        );
+        assert!(parse_table_names("Rel tables: \n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_node_table() {
+        let table = parse_node_table(
+            "User".to_owned(),
+            "User properties: \n\tname STRING(PRIMARY KEY)\n\tage INT64\n\ttags STRING[]\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            table,
+            NodeTable {
+                name: "User".to_owned(),
+                properties: vec![
+                    property("name", LogicalType::String),
+                    property("age", LogicalType::Int64),
+                    property("tags", LogicalType::VarList(Box::new(LogicalType::String))),
+                ],
+                primary_key: "name".to_owned(),
+            }
+        );
+
+        assert!(parse_node_table("User".to_owned(), "User properties: \n\tage INT64\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_rel_table() {
+        let table = parse_rel_table(
+            "Follows".to_owned(),
+            "Follows src node: User\nFollows dst node: City\nFollows properties: \n\tsince INT64\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            table,
+            RelTable {
+                name: "Follows".to_owned(),
+                src: "User".to_owned(),
+                dst: "City".to_owned(),
+                properties: vec![property("since", LogicalType::Int64)],
+            }
+        );
+
+        assert!(parse_rel_table("Follows".to_owned(), "Follows properties: \n").is_err());
+    }
+
+    #[test]
+    fn test_catalog() {
+        let (_dir, db) = test_database();
+        let conn = Connection::new(&db).unwrap();
+        conn.query(
+            "CREATE NODE TABLE User(name STRING, age INT64, scores DOUBLE[2], PRIMARY KEY (name));",
+        )
+        .unwrap();
+        conn.query("CREATE NODE TABLE City(id INT64, name STRING, PRIMARY KEY (id));")
+            .unwrap();
+        conn.query("CREATE REL TABLE LivesIn(FROM User TO City, since DATE);")
+            .unwrap();
+        conn.query("CREATE REL TABLE Follows(FROM User TO User);")
+            .unwrap();
+
+        let catalog = conn.catalog().unwrap();
+        assert_eq!(
+            catalog.node_table("User"),
+            Some(&NodeTable {
+                name: "User".to_owned(),
+                properties: vec![
+                    property("name", LogicalType::String),
+                    property("age", LogicalType::Int64),
+                    property(
+                        "scores",
+                        LogicalType::FixedList(Box::new(LogicalType::Double), 2)
+                    ),
+                ],
+                primary_key: "name".to_owned(),
+            })
+        );
+        assert_eq!(catalog.node_table("City").unwrap().primary_key, "id");
+        assert_eq!(
+            catalog.rel_table("LivesIn"),
+            Some(&RelTable {
+                name: "LivesIn".to_owned(),
+                src: "User".to_owned(),
+                dst: "City".to_owned(),
+                properties: vec![property("since", LogicalType::Date)],
+            })
+        );
+        assert_eq!(
+            catalog.rel_table("Follows"),
+            Some(&RelTable {
+                name: "Follows".to_owned(),
+                src: "User".to_owned(),
+                dst: "User".to_owned(),
+                properties: vec![],
+            })
+        );
+        assert_eq!(catalog.node_tables.len(), 2);
+        assert_eq!(catalog.rel_tables.len(), 2);
+    }
+}
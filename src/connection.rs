@@ -1,7 +1,18 @@
-use crate::{error, ptrc::PtrContainer};
+use crate::{
+    error,
+    prepared_statement::PreparedQuery,
+    ptrc::PtrContainer,
+    statement_cache::{self, StatementCache},
+};
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell, RefMut},
+    fmt,
     ops::{Deref, DerefMut},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    time::Duration,
 };
 
 use super::database;
@@ -12,18 +23,47 @@ use crate::ffi;
 pub struct Connection {
     /// The inner reference cell containing the pointer to the Kuzu connection.
     inner: RefCell<PtrContainer<ffi::kuzu_connection>>,
-    /// Transaction statistics for the connection.
-    tx_stats: TransactionStats,
+    /// The process-wide unique id of the connection.
+    id: ConnectionId,
+    /// The state shared with the interrupt handles of the connection.
+    interrupt_target: Arc<Mutex<InterruptTarget>>,
+    /// The query timeout of the connection, `Duration::ZERO` if there is none.
+    query_timeout: Cell<Duration>,
+    /// The prepared statements cached by `prepare_cached`.
+    statement_cache: RefCell<StatementCache<PreparedQuery>>,
+    /// The transaction left open by `DropBehaviour::Ignore`, released from the transaction
+    /// statistics once the connection is dropped.
+    ignored_transaction: Cell<Option<TransactionType>>,
+    /// Whether a `Transaction` on the connection is open, so no other one can begin through it.
+    transaction_open: Cell<bool>,
+    /// The database of the connection, kept alive until the connection is dropped.
+    database: database::Database,
 }
 
 impl Connection {
     /// Creates a new connection to the specified database.
-    pub fn new(database: &mut database::Database) -> error::Result<Self> {
+    ///
+    /// A connection is `Send`, so it can be moved to a worker thread, but not `Sync`.
+    /// Open one connection per thread to run queries concurrently.
+    pub fn new(database: &database::Database) -> error::Result<Self> {
         unsafe {
             let this = PtrContainer::try_new(ffi::kuzu_connection_init(database.inner.0))?;
+            let interrupt_target = Arc::new(Mutex::new(InterruptTarget {
+                conn: Some(ConnectionPtr(this.0)),
+                generation: 0,
+                interrupted: None,
+            }));
             Ok(Self {
                 inner: RefCell::new(this),
-                tx_stats: TransactionStats::default(),
+                id: ConnectionId::next(),
+                interrupt_target,
+                query_timeout: Cell::new(Duration::ZERO),
+                statement_cache: RefCell::new(StatementCache::new(
+                    statement_cache::DEFAULT_CAPACITY,
+                )),
+                ignored_transaction: Cell::new(None),
+                transaction_open: Cell::new(false),
+                database: database.clone(),
             })
         }
     }
@@ -38,25 +78,214 @@ impl Connection {
         unsafe { ffi::kuzu_connection_get_max_num_thread_for_exec(self.to_inner()) }
     }
 
+    /// Sets the timeout after which the queries of the connection are aborted with `Error::Timeout`.
+    /// A timeout of `Duration::ZERO` disables it.
+    pub fn set_query_timeout(&mut self, timeout: Duration) {
+        self.apply_query_timeout(timeout)
+    }
+
+    /// Returns the query timeout of the connection, if there is one.
+    pub fn query_timeout(&self) -> Option<Duration> {
+        let timeout = self.query_timeout.get();
+        (!timeout.is_zero()).then_some(timeout)
+    }
+
+    /// Applies the query timeout to the inner connection.
+    pub(crate) fn apply_query_timeout(&self, timeout: Duration) {
+        // Round sub-millisecond timeouts up, since a timeout of 0ms disables it.
+        let timeout_in_ms = match timeout.as_millis() {
+            0 if !timeout.is_zero() => 1,
+            ms => ms.try_into().unwrap_or(u64::MAX),
+        };
+        unsafe { ffi::kuzu_connection_set_query_timeout(self.to_inner(), timeout_in_ms) };
+        self.query_timeout.set(timeout);
+    }
+
+    /// Runs a query on the inner connection, telling an interrupt requested through an
+    /// `InterruptHandle` apart from a query timeout, which Kuzu reports the same way.
+    ///
+    /// An interrupt counts towards the query of the generation it was requested in, so one landing
+    /// right before the query starts is still reported as `Error::Interrupted`. The request is
+    /// cleared only once the query returns, along with moving on to the next generation.
+    pub(crate) fn run_query<T>(
+        &self,
+        query: impl FnOnce() -> error::Result<T>,
+    ) -> error::Result<T> {
+        let generation = self.interrupt_target().generation;
+        let res = query();
+
+        let interrupted = {
+            let mut target = self.interrupt_target();
+            target.generation += 1;
+            target.interrupted.take() == Some(generation)
+        };
+        match res {
+            Err(error::Error::Interrupted) if !interrupted => Err(error::Error::Timeout),
+            res => res,
+        }
+    }
+
+    /// Locks the state shared with the interrupt handles.
+    fn interrupt_target(&self) -> MutexGuard<'_, InterruptTarget> {
+        self.interrupt_target
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Borrows the prepared statements cached by the connection.
+    pub(crate) fn statement_cache(&self) -> RefMut<'_, StatementCache<PreparedQuery>> {
+        self.statement_cache.borrow_mut()
+    }
+
+    /// Returns the id of the connection.
+    pub fn id(&self) -> ConnectionId {
+        self.id
+    }
+
+    /// Returns the database of the connection.
+    pub fn database(&self) -> &database::Database {
+        &self.database
+    }
+
+    /// Returns a handle that can interrupt the queries running on this connection from another thread.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle {
+            target: Arc::clone(&self.interrupt_target),
+        }
+    }
+
+    /// Returns whether a transaction was left open on the connection by `DropBehaviour::Ignore`.
+    pub(crate) fn has_ignored_transaction(&self) -> bool {
+        self.ignored_transaction.get().is_some()
+    }
+
     /// Returns the inner pointer to the `kuzu_connection` struct.
     pub(crate) fn to_inner(&self) -> *mut ffi::kuzu_connection {
         self.inner.borrow().0
     }
 
     /// Begins a new transaction with the specified transaction type.
-    pub fn transaction(&mut self, transaction_type: TransactionType) -> error::Result<Transaction> {
-        match transaction_type {
-            TransactionType::ReadWrite if self.tx_stats.rw > 0 => {
-                return Err(error::Error::MultipleWriteTxNotAllowed)
+    ///
+    /// Any number of read-only transactions can be open across the connections to a database,
+    /// alongside at most one read-write transaction.
+    /// Returns `Error::WriteTransactionActive` if another read-write transaction is open.
+    /// Returns `Error::IgnoredTransactionOpen` if a transaction was left open on the connection by `DropBehaviour::Ignore`.
+    /// Returns `Error::TransactionAlreadyOpen` if called through an open transaction of the connection.
+    /// Returns the error of the engine if it refuses to begin the transaction.
+    pub fn transaction(
+        &mut self,
+        transaction_type: TransactionType,
+    ) -> error::Result<Transaction<'_>> {
+        Transaction::new(self, transaction_type)
+    }
+
+    /// Runs `f` inside a new transaction with the specified transaction type.
+    ///
+    /// The transaction is committed if `f` returns `Ok`, and rolled back if it returns `Err` or panics.
+    /// Returns the error of the commit if it fails, in which case the changes were not persisted.
+    pub fn with_transaction<T, F>(
+        &mut self,
+        transaction_type: TransactionType,
+        f: F,
+    ) -> error::Result<T>
+    where
+        F: FnOnce(&mut Transaction) -> error::Result<T>,
+    {
+        let mut tx = self.transaction(transaction_type)?;
+        // Rolls back if `f` panics and the transaction is dropped while unwinding.
+        tx.set_drop_behaviour(DropBehaviour::RollBack);
+
+        match f(&mut tx) {
+            Ok(value) => {
+                if tx.is_active() {
+                    tx.commit()?;
+                }
+                Ok(value)
+            }
+            Err(err) => {
+                // The error of `f` is more relevant than a failed rollback,
+                // e.g. when the engine already rolled back the transaction.
+                if tx.is_active() {
+                    let _ = tx.rollback();
+                }
+                Err(err)
             }
-            TransactionType::ReadWrite => self.tx_stats.rw += 1,
-            TransactionType::Readonly => self.tx_stats.r += 1,
         }
-        Ok(Transaction::new(self, transaction_type))
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        // Detach the interrupt handles and destroy the cached statements
+        // before the inner connection is destroyed.
+        self.interrupt_target().conn.take();
+        self.statement_cache.get_mut().clear();
+
+        // The engine closes the transaction together with the connection.
+        if let Some(transaction_type) = self.ignored_transaction.take() {
+            self.database.tx_stats().end(self.id, transaction_type);
+        }
+    }
+}
+
+/// The unique id of a `Connection`, used to tell which connection holds the write transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConnectionId(u64);
+
+impl ConnectionId {
+    /// Returns a new id, distinct from the ids of all the connections created before.
+    fn next() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl fmt::Display for ConnectionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// Raw connection pointer that can be shared with other threads.
+struct ConnectionPtr(*mut ffi::kuzu_connection);
+
+// SAFETY: the pointer is only used for `kuzu_connection_interrupt`, which is thread-safe,
+// and it is removed under the mutex before the connection is destroyed.
+unsafe impl Send for ConnectionPtr {}
+
+/// State shared between a `Connection` and its interrupt handles.
+struct InterruptTarget {
+    /// The connection to interrupt, `None` once the connection is dropped.
+    conn: Option<ConnectionPtr>,
+    /// The generation of the current query, incremented each time a query returns.
+    generation: u64,
+    /// The generation of the query an interrupt was requested for, if any.
+    interrupted: Option<u64>,
+}
+
+/// A cloneable handle for interrupting the queries running on a `Connection`.
+///
+/// The handle is `Send + Sync`, so it can be moved to a watchdog thread or a Ctrl-C handler.
+/// An interrupted `query` or `execute` returns `Error::Interrupted`.
+/// Interrupting after the connection has been dropped does nothing.
+#[derive(Clone)]
+pub struct InterruptHandle {
+    target: Arc<Mutex<InterruptTarget>>,
+}
+
+impl InterruptHandle {
+    /// Interrupts the query currently running on the connection, if any.
+    pub fn interrupt(&self) {
+        let mut target = self.target.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(ConnectionPtr(conn)) = target.conn {
+            target.interrupted = Some(target.generation);
+            unsafe { ffi::kuzu_connection_interrupt(conn) }
+        }
     }
 }
 
 /// Represents the type of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TransactionType {
     ///  Indicates a read-only transaction. This type of transaction is used for executing read operations on the database without modifying the data.
     Readonly,
@@ -64,25 +293,68 @@ pub enum TransactionType {
     ReadWrite,
 }
 
-/// Represents the behavior when dropping a transaction.
+/// Represents the behavior when dropping a transaction that was neither committed nor rolled back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DropBehaviour {
     /// Roll back the changes made in the transaction when it is dropped.
     RollBack,
     /// Commit the changes made in the transaction when it is dropped.
     Commit,
-    /// Ignore the changes made in the transaction when it is dropped.
+    /// Leave the transaction open on the connection when it is dropped.
+    /// It counts towards the transaction statistics of the database until the connection is dropped,
+    /// so a read-write transaction keeps the other connections from beginning one until then.
     Ignore,
     /// Panic when the transaction is dropped, indicating an unexpected situation.
+    /// Falls back to rolling back if the thread is already panicking.
     Panic,
 }
 
-/// Holds transaction statistics.
-#[derive(Default)]
+/// Holds the transaction statistics of a database, shared by all its connections.
+#[derive(Debug, Clone, Default)]
 pub struct TransactionStats {
-    /// The number of read-only transactions.
-    r: usize,
-    /// The number of read-write transactions.
-    rw: usize,
+    /// The number of open read-only transactions.
+    readers: usize,
+    /// The connection with an open read-write transaction, if any.
+    writer: Option<ConnectionId>,
+}
+
+impl TransactionStats {
+    /// Returns the number of open read-only transactions.
+    pub fn readers(&self) -> usize {
+        self.readers
+    }
+
+    /// Returns the connection with an open read-write transaction, if any.
+    pub fn writer(&self) -> Option<ConnectionId> {
+        self.writer
+    }
+
+    /// Registers a new transaction of the specified connection.
+    fn begin(
+        &mut self,
+        conn: ConnectionId,
+        transaction_type: TransactionType,
+    ) -> error::Result<()> {
+        match transaction_type {
+            TransactionType::Readonly => self.readers += 1,
+            TransactionType::ReadWrite => match self.writer {
+                Some(writer) => return Err(error::Error::WriteTransactionActive(writer)),
+                None => self.writer = Some(conn),
+            },
+        }
+        Ok(())
+    }
+
+    /// Unregisters a transaction of the specified connection.
+    fn end(&mut self, conn: ConnectionId, transaction_type: TransactionType) {
+        match transaction_type {
+            TransactionType::Readonly => self.readers -= 1,
+            TransactionType::ReadWrite => {
+                debug_assert_eq!(self.writer, Some(conn));
+                self.writer = None;
+            }
+        }
+    }
 }
 
 /// Represents a transaction within a connection.
@@ -90,52 +362,136 @@ pub struct Transaction<'conn> {
     conn: &'conn mut Connection,
     transaction_type: TransactionType,
     on_drop: DropBehaviour,
-    commited: bool,
+    /// Whether the transaction was neither committed nor rolled back yet.
+    active: bool,
+    /// Whether the transaction has been released from the connection's transaction statistics.
+    finished: bool,
 }
 
 impl<'c> Transaction<'c> {
     /// Creates a new transaction with the specified connection and transaction type.
-    fn new(conn: &'c mut Connection, transaction_type: TransactionType) -> Transaction<'_> {
-        unsafe {
-            match transaction_type {
-                TransactionType::Readonly => {
-                    ffi::kuzu_connection_begin_read_only_transaction(conn.to_inner())
-                }
-                TransactionType::ReadWrite => {
-                    ffi::kuzu_connection_begin_write_transaction(conn.to_inner())
-                }
-            }
+    ///
+    /// Returns `Error::WriteTransactionActive` if a read-write transaction is requested while
+    /// another connection to the database holds one.
+    /// Returns `Error::IgnoredTransactionOpen` if a transaction was left open on the connection.
+    /// Returns `Error::TransactionAlreadyOpen` if a transaction is open on the connection.
+    ///
+    /// The transaction statements are run as queries, since the C API functions for beginning,
+    /// committing and rolling back a transaction don't report failures.
+    fn new(
+        conn: &'c mut Connection,
+        transaction_type: TransactionType,
+    ) -> error::Result<Transaction<'c>> {
+        if conn.has_ignored_transaction() {
+            return Err(error::Error::IgnoredTransactionOpen);
+        }
+        if conn.transaction_open.get() {
+            return Err(error::Error::TransactionAlreadyOpen);
         }
+        conn.database.tx_stats().begin(conn.id, transaction_type)?;
 
-        Self {
+        let begin = match transaction_type {
+            TransactionType::Readonly => "BEGIN TRANSACTION READ ONLY;",
+            TransactionType::ReadWrite => "BEGIN TRANSACTION;",
+        };
+        if let Err(e) = conn.query(begin) {
+            conn.database.tx_stats().end(conn.id, transaction_type);
+            return Err(e);
+        }
+        conn.transaction_open.set(true);
+
+        Ok(Self {
             conn,
             transaction_type,
-            commited: false,
             on_drop: DropBehaviour::RollBack,
-        }
+            active: true,
+            finished: false,
+        })
+    }
+
+    /// Returns whether the transaction was neither committed nor rolled back yet.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Sets what happens when the transaction is dropped without being committed or rolled back.
+    /// Default value is `DropBehaviour::RollBack`.
+    pub fn set_drop_behaviour(&mut self, on_drop: DropBehaviour) {
+        self.on_drop = on_drop;
+    }
+
+    /// Returns what happens when the transaction is dropped without being committed or rolled back.
+    pub fn drop_behaviour(&self) -> DropBehaviour {
+        self.on_drop
     }
 
     /// Commits the transaction.
-    pub fn commit(&mut self) {
-        self.commited = true;
-        unsafe { ffi::kuzu_connection_commit(self.conn.to_inner()) }
+    ///
+    /// Returns `Error::TransactionNotActive` if the transaction was already committed or rolled back,
+    /// or the error of the engine if it fails to commit it, e.g. because a failed statement aborted
+    /// the transaction. The transaction stays active after a failed commit, so it is still rolled back on drop.
+    pub fn commit(&mut self) -> error::Result<()> {
+        if !self.active {
+            return Err(error::Error::TransactionNotActive);
+        }
+        self.conn.query("COMMIT;")?;
+        self.active = false;
+        Ok(())
     }
 
     /// Rolls back the transaction.
-    pub fn rollback(&self) {
-        unsafe { ffi::kuzu_connection_rollback(self.conn.to_inner()) }
+    ///
+    /// Returns `Error::TransactionNotActive` if the transaction was already committed or rolled back,
+    /// or the error of the engine if it fails to roll it back. The transaction is no longer active
+    /// afterwards in either case.
+    pub fn rollback(&mut self) -> error::Result<()> {
+        if !self.active {
+            return Err(error::Error::TransactionNotActive);
+        }
+        self.active = false;
+        self.conn.query("ROLLBACK;")?;
+        Ok(())
     }
 
-    /// Finishes the transaction, either by committing or rolling back.
+    /// Finishes the transaction, handling a pending transaction according to its `DropBehaviour`.
     pub fn finish(&mut self) {
-        if !self.commited {
-            self.rollback()
+        if self.finished {
+            return;
         }
+        self.finished = true;
+        self.conn.transaction_open.set(false);
 
-        match self.transaction_type {
-            TransactionType::ReadWrite => self.conn.tx_stats.rw -= 1,
-            TransactionType::Readonly => self.conn.tx_stats.r -= 1,
+        // Errors can't be reported from here, a failed commit falls back to rolling back.
+        if self.active {
+            match self.on_drop {
+                DropBehaviour::RollBack => {
+                    let _ = self.rollback();
+                }
+                DropBehaviour::Commit => {
+                    if self.commit().is_err() {
+                        let _ = self.rollback();
+                    }
+                }
+                DropBehaviour::Ignore => {
+                    self.conn
+                        .ignored_transaction
+                        .set(Some(self.transaction_type));
+                    return;
+                }
+                DropBehaviour::Panic if std::thread::panicking() => {
+                    let _ = self.rollback();
+                }
+                DropBehaviour::Panic => {
+                    let _ = self.rollback();
+                    panic!("transaction finished without being committed or rolled back");
+                }
+            }
         }
+
+        self.conn
+            .database
+            .tx_stats()
+            .end(self.conn.id, self.transaction_type);
     }
 }
 
@@ -160,3 +516,357 @@ impl Drop for Transaction<'_> {
         self.finish();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        panic::{catch_unwind, AssertUnwindSafe},
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        thread,
+        time::Duration,
+    };
+
+    use super::{
+        Connection, ConnectionId, DropBehaviour, InterruptHandle, TransactionStats, TransactionType,
+    };
+    use crate::{database::Database, error::Error, test_utils::test_database};
+
+    /// A query running long enough to be interrupted.
+    const LONG_QUERY: &str = "UNWIND range(1, 1000000000) AS x RETURN sum(x);";
+
+    fn assert_send<T: Send>() {}
+    fn assert_send_sync<T: Send + Sync + Clone>() {}
+
+    /// Creates the `User` table used by the transaction tests.
+    fn create_user_table(db: &Database) -> Connection {
+        let conn = Connection::new(db).unwrap();
+        conn.query("CREATE NODE TABLE User(name STRING, PRIMARY KEY (name));")
+            .unwrap();
+        conn
+    }
+
+    /// Returns the number of users committed to the database, as seen by a new connection.
+    fn count_users(db: &Database) -> i64 {
+        Connection::new(db)
+            .unwrap()
+            .query("MATCH (u:User) RETURN count(u);")
+            .unwrap()
+            .iter::<(i64,)>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_interrupt_handle_is_send_sync() {
+        assert_send_sync::<InterruptHandle>();
+    }
+
+    #[test]
+    fn test_database_is_send_sync() {
+        assert_send_sync::<Database>();
+        assert_send_sync::<std::sync::Arc<Database>>();
+    }
+
+    #[test]
+    fn test_connection_is_send() {
+        assert_send::<Connection>();
+    }
+
+    #[test]
+    fn test_transaction_stats_single_writer() {
+        let (first, second) = (ConnectionId::next(), ConnectionId::next());
+        let mut stats = TransactionStats::default();
+
+        stats.begin(first, TransactionType::ReadWrite).unwrap();
+        stats.begin(second, TransactionType::Readonly).unwrap();
+        stats.begin(first, TransactionType::Readonly).unwrap();
+        assert_eq!(stats.readers(), 2);
+        assert_eq!(stats.writer(), Some(first));
+
+        match stats.begin(second, TransactionType::ReadWrite) {
+            Err(Error::WriteTransactionActive(writer)) => assert_eq!(writer, first),
+            res => panic!("expected WriteTransactionActive, got {res:?}"),
+        }
+
+        stats.end(first, TransactionType::ReadWrite);
+        stats.begin(second, TransactionType::ReadWrite).unwrap();
+        assert_eq!(stats.writer(), Some(second));
+    }
+
+    #[test]
+    fn test_interrupt_from_another_thread() {
+        let (_dir, db) = test_database();
+        let conn = Connection::new(&db).unwrap();
+        let handle = conn.interrupt_handle();
+        let done = Arc::new(AtomicBool::new(false));
+
+        // Keep interrupting, as the query may not have started when the first interrupt lands.
+        let interrupter = thread::spawn({
+            let done = Arc::clone(&done);
+            move || {
+                while !done.load(Ordering::Acquire) {
+                    handle.interrupt();
+                    thread::sleep(Duration::from_millis(10));
+                }
+            }
+        });
+        let res = conn.query(LONG_QUERY);
+        done.store(true, Ordering::Release);
+        interrupter.join().unwrap();
+
+        assert!(
+            matches!(res, Err(Error::Interrupted)),
+            "got {:?}",
+            res.err()
+        );
+    }
+
+    #[test]
+    fn test_query_timeout() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+
+        conn.set_query_timeout(Duration::from_millis(1));
+        assert_eq!(conn.query_timeout(), Some(Duration::from_millis(1)));
+        assert!(matches!(conn.query(LONG_QUERY), Err(Error::Timeout)));
+
+        // An interrupt requested before the query starts is reported as such, not as a timeout.
+        conn.interrupt_handle().interrupt();
+        assert!(matches!(conn.query(LONG_QUERY), Err(Error::Interrupted)));
+        assert!(matches!(conn.query(LONG_QUERY), Err(Error::Timeout)));
+
+        conn.set_query_timeout(Duration::ZERO);
+        assert_eq!(conn.query_timeout(), None);
+        {
+            let statement = conn.prepare(LONG_QUERY).unwrap();
+            let res = statement.execute_with_timeout(Duration::from_micros(1));
+            assert!(matches!(res, Err(Error::Timeout)), "got {:?}", res.err());
+        }
+        assert_eq!(conn.query_timeout(), None);
+    }
+
+    #[test]
+    fn test_connections_on_threads() {
+        let (_dir, db) = test_database();
+        let conn = Connection::new(&db).unwrap();
+        conn.query("CREATE NODE TABLE Counter(id INT64, PRIMARY KEY (id));")
+            .unwrap();
+        for id in 0..4 {
+            conn.query(format!("CREATE (:Counter {{id: {id}}});"))
+                .unwrap();
+        }
+
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let db = db.clone();
+                thread::spawn(move || {
+                    let conn = Connection::new(&db).unwrap();
+                    let mut ids = conn
+                        .query("MATCH (c:Counter) RETURN c.id;")
+                        .unwrap()
+                        .iter::<(i64,)>()
+                        .unwrap()
+                        .map(|row| row.unwrap().0)
+                        .collect::<Vec<_>>();
+                    ids.sort_unstable();
+                    ids
+                })
+            })
+            .collect();
+        for worker in workers {
+            assert_eq!(worker.join().unwrap(), [0, 1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn test_ignored_write_transaction_keeps_slot() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+        let id = conn.id();
+
+        let mut tx = conn.transaction(TransactionType::ReadWrite).unwrap();
+        tx.set_drop_behaviour(DropBehaviour::Ignore);
+        drop(tx);
+        assert_eq!(db.transaction_stats().writer(), Some(id));
+        assert!(matches!(
+            conn.transaction(TransactionType::Readonly),
+            Err(Error::IgnoredTransactionOpen)
+        ));
+
+        let mut other = Connection::new(&db).unwrap();
+        match other.transaction(TransactionType::ReadWrite) {
+            Err(Error::WriteTransactionActive(writer)) => assert_eq!(writer, id),
+            res => panic!("expected WriteTransactionActive, got {:?}", res.err()),
+        }
+
+        drop(conn);
+        assert_eq!(db.transaction_stats().writer(), None);
+        other.transaction(TransactionType::ReadWrite).unwrap();
+    }
+
+    #[test]
+    fn test_with_transaction_commits_on_ok() {
+        let (_dir, db) = test_database();
+        let mut conn = create_user_table(&db);
+
+        let name = conn
+            .with_transaction(TransactionType::ReadWrite, |tx| {
+                tx.query("CREATE (:User {name: 'Alice'});")?;
+                Ok("Alice")
+            })
+            .unwrap();
+        assert_eq!(name, "Alice");
+        assert_eq!(count_users(&db), 1);
+        assert_eq!(db.transaction_stats().writer(), None);
+    }
+
+    #[test]
+    fn test_with_transaction_rolls_back_on_err() {
+        let (_dir, db) = test_database();
+        let mut conn = create_user_table(&db);
+
+        let res = conn.with_transaction(TransactionType::ReadWrite, |tx| {
+            tx.query("CREATE (:User {name: 'Alice'});")?;
+            Err::<(), _>(Error::QueryResultError("failed".to_owned()))
+        });
+        assert!(matches!(res, Err(Error::QueryResultError(message)) if message == "failed"));
+        assert_eq!(count_users(&db), 0);
+        assert_eq!(db.transaction_stats().writer(), None);
+    }
+
+    #[test]
+    fn test_with_transaction_rolls_back_on_panic() {
+        let (_dir, db) = test_database();
+        let mut conn = create_user_table(&db);
+
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            conn.with_transaction::<(), _>(TransactionType::ReadWrite, |tx| {
+                tx.query("CREATE (:User {name: 'Alice'});")?;
+                panic!("failed inside the transaction");
+            })
+        }));
+        assert!(res.is_err());
+        assert_eq!(count_users(&db), 0);
+        assert_eq!(db.transaction_stats().writer(), None);
+        conn.transaction(TransactionType::ReadWrite).unwrap();
+    }
+
+    #[test]
+    fn test_drop_behaviour_commit_and_rollback() {
+        let (_dir, db) = test_database();
+        let mut conn = create_user_table(&db);
+
+        let mut tx = conn.transaction(TransactionType::ReadWrite).unwrap();
+        tx.set_drop_behaviour(DropBehaviour::Commit);
+        tx.query("CREATE (:User {name: 'Alice'});").unwrap();
+        drop(tx);
+        assert_eq!(count_users(&db), 1);
+
+        let tx = conn.transaction(TransactionType::ReadWrite).unwrap();
+        assert_eq!(tx.drop_behaviour(), DropBehaviour::RollBack);
+        tx.query("CREATE (:User {name: 'Bob'});").unwrap();
+        drop(tx);
+        assert_eq!(count_users(&db), 1);
+        assert_eq!(db.transaction_stats().writer(), None);
+    }
+
+    #[test]
+    fn test_drop_behaviour_panic() {
+        let (_dir, db) = test_database();
+        let mut conn = create_user_table(&db);
+
+        let res = catch_unwind(AssertUnwindSafe(|| {
+            let mut tx = conn.transaction(TransactionType::ReadWrite).unwrap();
+            tx.set_drop_behaviour(DropBehaviour::Panic);
+            tx.query("CREATE (:User {name: 'Alice'});").unwrap();
+        }));
+        assert!(res.is_err());
+        assert_eq!(count_users(&db), 0);
+        assert_eq!(db.transaction_stats().writer(), None);
+
+        // A finished transaction doesn't panic.
+        let mut tx = conn.transaction(TransactionType::ReadWrite).unwrap();
+        tx.set_drop_behaviour(DropBehaviour::Panic);
+        tx.query("CREATE (:User {name: 'Bob'});").unwrap();
+        tx.commit().unwrap();
+        drop(tx);
+        assert_eq!(count_users(&db), 1);
+    }
+
+    #[test]
+    fn test_drop_behaviour_ignore() {
+        let (_dir, db) = test_database();
+        let mut conn = create_user_table(&db);
+
+        let mut tx = conn.transaction(TransactionType::ReadWrite).unwrap();
+        tx.set_drop_behaviour(DropBehaviour::Ignore);
+        tx.query("CREATE (:User {name: 'Alice'});").unwrap();
+        drop(tx);
+        // The transaction is still open on the connection, so its changes are not committed.
+        assert_eq!(count_users(&db), 0);
+
+        drop(conn);
+        assert_eq!(count_users(&db), 0);
+        assert_eq!(db.transaction_stats().writer(), None);
+    }
+
+    #[test]
+    fn test_failed_commit() {
+        let (_dir, db) = test_database();
+        let mut conn = create_user_table(&db);
+        conn.query("CREATE (:User {name: 'Alice'});").unwrap();
+
+        let mut tx = conn.transaction(TransactionType::ReadWrite).unwrap();
+        tx.query("CREATE (:User {name: 'Bob'});").unwrap();
+        assert!(matches!(
+            tx.query("CREATE (:User {name: 'Alice'});"),
+            Err(Error::ConstraintViolation(_))
+        ));
+        // The failed statement aborted the transaction, so it can't be committed.
+        assert!(tx.commit().is_err());
+        assert!(tx.is_active());
+        drop(tx);
+
+        assert_eq!(count_users(&db), 1);
+        assert_eq!(db.transaction_stats().writer(), None);
+    }
+
+    #[test]
+    fn test_ddl_in_transaction() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+
+        let tx = conn.transaction(TransactionType::ReadWrite).unwrap();
+        assert!(matches!(
+            tx.query("CREATE NODE TABLE City(name STRING, PRIMARY KEY (name));"),
+            Err(Error::TxNotAllowed)
+        ));
+        drop(tx);
+
+        assert!(conn.node_table_names().unwrap().is_empty());
+        assert_eq!(db.transaction_stats().writer(), None);
+    }
+
+    #[test]
+    fn test_nested_transaction() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+
+        let mut tx = conn.transaction(TransactionType::ReadWrite).unwrap();
+        assert!(matches!(
+            tx.transaction(TransactionType::Readonly),
+            Err(Error::TransactionAlreadyOpen)
+        ));
+        assert_eq!(db.transaction_stats().readers(), 0);
+        tx.commit().unwrap();
+        drop(tx);
+
+        conn.transaction(TransactionType::Readonly).unwrap();
+    }
+}
@@ -1,6 +1,9 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
 use crate::ffi;
 use crate::into_cstr;
 
+use crate::connection::TransactionStats;
 use crate::error;
 use crate::ptrc::PtrContainer;
 
@@ -64,9 +67,39 @@ impl DatabaseBuilder {
 }
 
 /// Represents a database instance.
-#[repr(C)]
+///
+/// `Database` is `Send + Sync` and cheap to clone: clones share the same underlying database,
+/// which is closed once the last clone and the last `Connection` to it are dropped.
+/// Open one `Connection` per thread to run queries concurrently.
+///
+/// ```no_run
+/// use kuzu_rs::{connection::Connection, database::Database};
+///
+/// # fn main() -> kuzu_rs::error::Result<()> {
+/// let db = Database::builder("test_db").build()?;
+///
+/// let workers: Vec<_> = (0..4)
+///     .map(|_| {
+///         let db = db.clone();
+///         std::thread::spawn(move || -> kuzu_rs::error::Result<()> {
+///             let conn = Connection::new(&db)?;
+///             conn.query("RETURN 1;")?;
+///             Ok(())
+///         })
+///     })
+///     .collect();
+///
+/// for worker in workers {
+///     worker.join().unwrap()?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
 pub struct Database {
-    pub(crate) inner: PtrContainer<ffi::kuzu_database>,
+    pub(crate) inner: Arc<PtrContainer<ffi::kuzu_database>>,
+    /// The transactions open on the connections to the database.
+    tx_stats: Arc<Mutex<TransactionStats>>,
 }
 
 impl Database {
@@ -80,7 +113,18 @@ impl Database {
         let this = PtrContainer::try_new(unsafe {
             ffi::kuzu_database_init(cstring_path.as_ptr(), buffer_pool_size)
         })?;
-        Ok(Self { inner: this })
+        Ok(Self {
+            inner: Arc::new(this),
+            tx_stats: Arc::default(),
+        })
+    }
+    /// Returns a snapshot of the transactions open on the connections to the database.
+    pub fn transaction_stats(&self) -> TransactionStats {
+        self.tx_stats().clone()
+    }
+    /// Locks the transactions open on the connections to the database.
+    pub(crate) fn tx_stats(&self) -> MutexGuard<'_, TransactionStats> {
+        self.tx_stats.lock().unwrap_or_else(PoisonError::into_inner)
     }
     /// Sets the logging level for the database.
     pub fn set_logging_level(log_level: &LogLevel) -> error::Result<()> {
@@ -1,5 +1,3 @@
-use std::ffi::c_char;
-
 use thiserror::Error;
 
 pub type Result<T, E = Error> = ::std::result::Result<T, E>;
@@ -11,42 +9,275 @@ pub enum Error {
     CStringEncodeError(String),
 
     /// Error that occurs while converting a C string into a Rust string.
-    #[error("Error while converting string {0:p} from CStr")]
-    CStringDecodeError(*const c_char),
+    #[error("Error while converting string {0} from CStr")]
+    CStringDecodeError(String),
 
     /// Error that occurs when a variant cannot be converted to a specific type.
     #[error("Cannot convert variant {0} to type {1}")]
     DecodeError(&'static str, &'static str),
 
+    /// Error that occurs when a value cannot be encoded for the Kuzu C API.
+    #[error("Cannot encode variant {0}, it is not supported by the C API")]
+    UnsupportedEncode(&'static str),
+
+    /// Error that occurs when a value is out of the range of the Kuzu type it is encoded as.
+    #[error("Cannot encode {0} as {1}, it is out of range")]
+    EncodeOutOfRange(&'static str, &'static str),
+
+    /// Error that occurs when a NULL value is decoded into a non-optional type.
+    #[error("Got NULL while decoding into non-optional type {0}, use Option<{0}> instead")]
+    UnexpectedNull(&'static str),
+
     /// Error that occurs when a column is not found.
     #[error("Column {0} not found. ({1:?})")]
     ColumnNotFound(String, Vec<String>),
 
-    /// Error that occurs while getting a query result.
+    /// Error that occurs while decoding the value of a specific column.
+    #[error("Error while decoding column {column}: {source}")]
+    ColumnDecodeError {
+        /// The name of the column that failed to decode.
+        column: String,
+        /// The underlying decoding error.
+        source: Box<Error>,
+    },
+
+    /// Error that occurs while reading a row of a query result.
+    #[error("Error while reading row {row}: {source}")]
+    RowError {
+        /// The zero-based index of the row in the query result.
+        row: usize,
+        /// The underlying error.
+        source: Box<Error>,
+    },
+
+    /// Error that occurs while getting a query result, when the failure doesn't fall in a more specific category.
     #[error("Error while getting query result: {0}")]
     QueryResultError(String),
 
+    /// Error indicating that the query is not valid Cypher.
+    #[error("Parser error: {message}")]
+    ParserError {
+        /// The message of the parser, without the position.
+        message: String,
+        /// The one-based line of the error in the query, if reported.
+        line: Option<u32>,
+        /// The zero-based column of the error in the line, if reported.
+        column: Option<u32>,
+    },
+
+    /// Error indicating that the query refers to something the database doesn't know,
+    /// e.g. an unknown table or property, or that its expressions don't type check.
+    #[error("Binder error: {0}")]
+    BinderError(String),
+
+    /// Error that occurs while executing the query.
+    #[error("Runtime error: {0}")]
+    RuntimeError(String),
+
+    /// Error indicating that the query violates a constraint, e.g. a duplicate primary key.
+    #[error("Constraint violation: {0}")]
+    ConstraintViolation(String),
+
+    /// Error that occurs while copying data from or to a file.
+    #[error("Copy error: {0}")]
+    CopyError(String),
+
+    /// Error that occurs while reading or writing files of the database.
+    #[error("IO error: {0}")]
+    IoError(String),
+
+    /// Error indicating that the query was interrupted through an `InterruptHandle`.
+    #[error("The query was interrupted")]
+    Interrupted,
+
+    /// Error indicating that the query ran longer than the query timeout.
+    #[error("The query exceeded the query timeout")]
+    Timeout,
+
     /// Error that occurs while trying to create a connection.
     #[error("Error while trying to create a connection: {0}")]
     ConnectionError(String),
 
+    /// Error that occurs when a type name returned by Kuzu cannot be parsed into a `LogicalType`.
+    #[error("Cannot parse logical type {0}")]
+    LogicalTypeParseError(String),
+
+    /// Error that occurs when the schema description returned by Kuzu cannot be parsed.
+    #[error("Cannot parse catalog entry: {0}")]
+    CatalogParseError(String),
+
+    /// Error indicating that an existing table doesn't match the Rust type describing it.
+    #[error("Table {0} already exists with a different schema")]
+    TableSchemaMismatch(String),
+
+    /// Error indicating that the blocking task running an async query panicked or was cancelled by the runtime.
+    #[error("The blocking task running the query failed: {0}")]
+    BlockingTaskFailed(String),
+
+    /// Error indicating that the sizes of a connection pool are invalid.
+    #[error("Invalid pool size, expected 0 < max_size ({max_size}) and min_size ({min_size}) <= max_size")]
+    InvalidPoolSize {
+        /// The configured minimum number of connections.
+        min_size: usize,
+        /// The configured maximum number of connections.
+        max_size: usize,
+    },
+
+    /// Error indicating that no pooled connection became available within the checkout timeout.
+    #[error("Timed out after {0:?} while waiting for a pooled connection")]
+    PoolTimeout(std::time::Duration),
+
     /// FFI error indicating that a null pointer was received instead of an expected pointer type.
     #[error("FFI error: expected {0} ptr got null ptr")]
     FFIGotNull(&'static str),
 
+    /// Error indicating that a parameter of a prepared statement was not bound before executing it.
+    #[error("Parameter ${0} of the prepared statement is not bound")]
+    MissingParameter(String),
+
+    /// Error indicating that a value was bound to a parameter the prepared statement doesn't have.
+    #[error("The prepared statement has no parameter ${0}")]
+    UnknownParameter(String),
+
     /// Error indicating that transactions are not allowed in DDL and COPY statements.
     #[error("Transactions are not allowed in DDL and COPY statements")]
     TxNotAllowed,
 
-    /// Error indicating that multiple write transactions are not allowed at the same time.
+    /// Error indicating that a write transaction was requested while another one is open.
+    #[deprecated(note = "a second write transaction fails with `Error::WriteTransactionActive`")]
     #[error(
         "At any point in time, there can be multiple read transactions but one write transaction"
     )]
     MultipleWriteTxNotAllowed,
 
+    /// Error indicating that a write transaction is already open on a connection to the database.
+    /// There can be multiple read transactions but only one write transaction at any point in time.
+    #[error("A write transaction is already open on connection {0}")]
+    WriteTransactionActive(crate::connection::ConnectionId),
+
+    /// Error indicating that a transaction was requested on a connection which still has one left open by `DropBehaviour::Ignore`.
+    #[error("A transaction left open by DropBehaviour::Ignore is still open on the connection")]
+    IgnoredTransactionOpen,
+
+    /// Error indicating that a transaction was requested through a transaction open on the same connection.
+    #[error("A transaction is already open on the connection")]
+    TransactionAlreadyOpen,
+
+    /// Error indicating that a transaction was already committed or rolled back.
+    #[error("The transaction was already committed or rolled back")]
+    TransactionNotActive,
+
     /// Error indicating that a list can only have one type inside it.
     #[error("A list can only have one type inside it")]
     ListTypeError,
-    // // Nothing
-    // Infallible,
+}
+
+/// The error message Kuzu reports for an interrupted query.
+const INTERRUPTED_MESSAGE: &str = "Interrupted.";
+
+/// Part of the error message Kuzu reports for a statement that can't run inside a transaction.
+const AUTO_COMMIT_ONLY_MESSAGE: &str = "can only run in the AUTO_COMMIT mode";
+
+impl Error {
+    /// Categorizes an error message reported by Kuzu for a failed query or prepared statement.
+    pub(crate) fn from_engine_message(message: String) -> Self {
+        if message == INTERRUPTED_MESSAGE {
+            return Error::Interrupted;
+        }
+        if message.contains(AUTO_COMMIT_ONLY_MESSAGE) {
+            return Error::TxNotAllowed;
+        }
+
+        let Some((kind, detail)) = message.split_once(" exception: ") else {
+            return Error::QueryResultError(message);
+        };
+        let detail = detail.to_owned();
+        match kind {
+            "Parser" => parse_parser_error(detail),
+            "Binder" | "Catalog" => Error::BinderError(detail),
+            "Copy" => Error::CopyError(detail),
+            "IO" => Error::IoError(detail),
+            "Runtime" if detail.contains("constraint") => Error::ConstraintViolation(detail),
+            "Runtime" => Error::RuntimeError(detail),
+            _ => Error::QueryResultError(message),
+        }
+    }
+}
+
+/// Splits the position, e.g. `(line: 1, offset: 4)`, and the quoted query off a parser error message.
+fn parse_parser_error(detail: String) -> Error {
+    let position = detail.rfind(" (line: ").and_then(|start| {
+        let (line, column) = detail[start + " (line: ".len()..]
+            .split_once(')')?
+            .0
+            .split_once(", offset: ")?;
+        Some((start, line.parse().ok()?, column.parse().ok()?))
+    });
+
+    match position {
+        Some((start, line, column)) => Error::ParserError {
+            message: detail[..start].to_owned(),
+            line: Some(line),
+            column: Some(column),
+        },
+        None => Error::ParserError {
+            message: detail,
+            line: None,
+            column: None,
+        },
+    }
+}
+
+impl From<std::convert::Infallible> for Error {
+    fn from(value: std::convert::Infallible) -> Self {
+        match value {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn test_from_engine_message() {
+        let err = Error::from_engine_message(
+            "Parser exception: Invalid input <MATC>: expected rule oC_Cypher (line: 2, offset: 4)\n\"MATC (n) RETURN n;\"".to_owned(),
+        );
+        match err {
+            Error::ParserError {
+                message,
+                line,
+                column,
+            } => {
+                assert_eq!(message, "Invalid input <MATC>: expected rule oC_Cypher");
+                assert_eq!((line, column), (Some(2), Some(4)));
+            }
+            err => panic!("expected ParserError, got {err:?}"),
+        }
+
+        assert!(matches!(
+            Error::from_engine_message("Binder exception: Table Foo does not exist.".to_owned()),
+            Error::BinderError(detail) if detail == "Table Foo does not exist."
+        ));
+        assert!(matches!(
+            Error::from_engine_message("Runtime exception: Found duplicated primary key value 1, which violates the uniqueness constraint of the primary key column.".to_owned()),
+            Error::ConstraintViolation(_)
+        ));
+        assert!(matches!(
+            Error::from_engine_message("Runtime exception: Division by zero.".to_owned()),
+            Error::RuntimeError(_)
+        ));
+        assert!(matches!(
+            Error::from_engine_message("Copy exception: Invalid UTF8-encoded string.".to_owned()),
+            Error::CopyError(_)
+        ));
+        assert!(matches!(
+            Error::from_engine_message("Interrupted.".to_owned()),
+            Error::Interrupted
+        ));
+        assert!(matches!(
+            Error::from_engine_message("Something went wrong".to_owned()),
+            Error::QueryResultError(_)
+        ));
+    }
 }
@@ -11,7 +11,7 @@ pub(crate) fn convert_inner_to_owned_string(inner: *const c_char) -> error::Resu
 
     Ok(cstr
         .to_str()
-        .map_err(|_| crate::error::Error::CStringDecodeError(inner))?
+        .map_err(|_| crate::error::Error::CStringDecodeError(cstr.to_string_lossy().into_owned()))?
         .to_owned())
 }
 
@@ -22,7 +22,7 @@ macro_rules! into_cstr {
         let cstr = ::std::ffi::CString::new(_inner)
             .map_err(|_| $crate::error::Error::CStringEncodeError(_inner.to_owned()))?;
 
-        Ok(cstr)
+        Ok::<_, $crate::error::Error>(cstr)
     }};
 }
 
@@ -30,3 +30,12 @@ pub(crate) enum CCow {
     Static(&'static CStr),
     Owned(CString),
 }
+
+impl CCow {
+    pub(crate) fn as_c_str(&self) -> &CStr {
+        match self {
+            CCow::Static(cstr) => cstr,
+            CCow::Owned(cstring) => cstring.as_c_str(),
+        }
+    }
+}
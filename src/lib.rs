@@ -1,6 +1,13 @@
 #![feature(concat_idents)]
 #![doc = include_str!("../README.md")]
 
+/// Module for inspecting the node and rel tables of a database.
+pub mod catalog;
+
+/// Module for running queries from async code on the tokio blocking pool.
+#[cfg(feature = "tokio")]
+pub mod async_connection;
+
 /// Module for handling database connections.
 pub mod connection;
 
@@ -10,12 +17,18 @@ pub mod database;
 /// Module containing error types and utilities.
 pub mod error;
 
+/// Module for pooling connections to a database.
+pub mod pool;
+
 /// Module for handling query results.
 pub mod query_result;
 
 /// Module for working with prepared statements.
 pub mod prepared_statement;
 
+/// Module for creating node and rel tables from Rust types.
+pub mod schema;
+
 /// Module defining various types used in the library.
 pub mod types;
 
@@ -26,5 +39,8 @@ pub(crate) use libkuzudb_sys as ffi;
 /// Module containing helper functions and types for internal use.
 pub(crate) mod helper;
 pub(crate) mod ptrc;
+pub(crate) mod statement_cache;
+#[cfg(test)]
+pub(crate) mod test_utils;
 
 pub use kuzu_macros as macros;
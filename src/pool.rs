@@ -0,0 +1,413 @@
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{connection::Connection, database::Database, error};
+
+/// Query used to check that a pooled connection is still usable.
+const HEALTH_CHECK_QUERY: &str = "RETURN 1;";
+
+/// Settings of a connection pool.
+#[derive(Clone)]
+struct PoolConfig {
+    /// The number of connections the pool keeps open, idle or checked out.
+    min_size: usize,
+    /// The maximum number of connections, idle and checked out.
+    max_size: usize,
+    /// How long `Pool::get` waits for a connection before failing.
+    checkout_timeout: Duration,
+    /// Whether a connection is checked with a trivial query on checkout.
+    health_check: bool,
+    /// The maximum number of threads used by each connection, reapplied on checkout.
+    max_num_thread_for_exec: Option<u64>,
+    /// The query timeout of each connection, reapplied on checkout.
+    query_timeout: Duration,
+    /// The statement cache capacity of each connection, reapplied on checkout.
+    statement_cache_capacity: Option<usize>,
+}
+
+/// Builder for creating a connection pool.
+pub struct PoolBuilder {
+    /// The database the connections are opened to.
+    database: Database,
+    /// The settings of the pool.
+    config: PoolConfig,
+}
+
+impl PoolBuilder {
+    /// Creates a new `PoolBuilder` for the specified database.
+    pub fn new(database: &Database) -> Self {
+        Self {
+            database: database.clone(),
+            config: PoolConfig {
+                min_size: 0,
+                max_size: 10,
+                checkout_timeout: Duration::from_secs(30),
+                health_check: true,
+                max_num_thread_for_exec: None,
+                query_timeout: Duration::ZERO,
+                statement_cache_capacity: None,
+            },
+        }
+    }
+    /// Sets the number of connections the pool keeps open. Default value is `0`.
+    ///
+    /// The connections are opened when the pool is built, and reopened when they are closed after failing the health check.
+    pub fn with_min_size(&mut self, min_size: usize) -> &mut Self {
+        self.config.min_size = min_size;
+        self
+    }
+    /// Sets the maximum number of connections of the pool. Default value is `10`.
+    pub fn with_max_size(&mut self, max_size: usize) -> &mut Self {
+        self.config.max_size = max_size;
+        self
+    }
+    /// Sets how long `Pool::get` waits for a free connection. Default value is 30 seconds.
+    pub fn with_checkout_timeout(&mut self, checkout_timeout: Duration) -> &mut Self {
+        self.config.checkout_timeout = checkout_timeout;
+        self
+    }
+    /// Sets whether connections are checked with a trivial query on checkout. Default value is `true`.
+    pub fn with_health_check(&mut self, health_check: bool) -> &mut Self {
+        self.config.health_check = health_check;
+        self
+    }
+    /// Sets the maximum number of threads each connection uses for executing queries.
+    pub fn with_max_num_thread_for_exec(&mut self, num_threads: u64) -> &mut Self {
+        self.config.max_num_thread_for_exec = Some(num_threads);
+        self
+    }
+    /// Sets the query timeout of each connection. A timeout of `Duration::ZERO` disables it.
+    pub fn with_query_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.config.query_timeout = timeout;
+        self
+    }
+    /// Sets the maximum number of prepared statements cached by each connection.
+    pub fn with_statement_cache_capacity(&mut self, capacity: usize) -> &mut Self {
+        self.config.statement_cache_capacity = Some(capacity);
+        self
+    }
+    /// Builds the pool and opens its initial connections.
+    pub fn build(&self) -> error::Result<Pool> {
+        let config = self.config.clone();
+        if config.max_size == 0 || config.min_size > config.max_size {
+            return Err(error::Error::InvalidPoolSize {
+                min_size: config.min_size,
+                max_size: config.max_size,
+            });
+        }
+
+        let idle = (0..config.min_size)
+            .map(|_| Connection::new(&self.database))
+            .collect::<error::Result<Vec<_>>>()?;
+
+        Ok(Pool {
+            shared: Arc::new(Shared {
+                database: self.database.clone(),
+                state: Mutex::new(PoolState {
+                    size: idle.len(),
+                    idle,
+                }),
+                available: Condvar::new(),
+                config,
+            }),
+        })
+    }
+}
+
+/// Connections of a pool.
+struct PoolState {
+    /// The connections that are not checked out.
+    idle: Vec<Connection>,
+    /// The number of open connections, idle and checked out.
+    size: usize,
+}
+
+/// State shared between a pool and its checked out connections.
+struct Shared {
+    /// The database the connections are opened to.
+    database: Database,
+    /// The settings of the pool.
+    config: PoolConfig,
+    /// The connections of the pool.
+    state: Mutex<PoolState>,
+    /// Notified whenever a connection is returned or closed.
+    available: Condvar,
+}
+
+impl Shared {
+    fn state(&self) -> MutexGuard<'_, PoolState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Forgets a connection that was closed instead of returned to the pool.
+    fn release_slot(&self) {
+        self.state().size -= 1;
+        self.available.notify_one();
+    }
+
+    /// Forgets a closed connection, and opens connections until the pool holds `min_size` of them again.
+    fn close(&self) {
+        self.release_slot();
+        self.replenish();
+    }
+
+    /// Opens idle connections until the pool holds `min_size` of them.
+    ///
+    /// Stops at the first connection that fails to open, a later checkout opens it instead.
+    fn replenish(&self) {
+        loop {
+            {
+                let mut state = self.state();
+                if state.size >= self.config.min_size {
+                    return;
+                }
+                state.size += 1;
+            }
+            match Connection::new(&self.database) {
+                Ok(conn) => {
+                    self.state().idle.push(conn);
+                    self.available.notify_one();
+                }
+                Err(_) => {
+                    self.release_slot();
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Reapplies the connection settings of the pool and runs the health check.
+    fn prepare(&self, conn: &mut Connection) -> error::Result<()> {
+        if let Some(num_threads) = self.config.max_num_thread_for_exec {
+            conn.set_max_num_thread_for_exec(num_threads);
+        }
+        conn.set_query_timeout(self.config.query_timeout);
+        if let Some(capacity) = self.config.statement_cache_capacity {
+            conn.set_statement_cache_capacity(capacity);
+        }
+
+        if self.config.health_check {
+            conn.query(HEALTH_CHECK_QUERY)?;
+        }
+        Ok(())
+    }
+}
+
+/// A thread-safe pool of connections to a database.
+///
+/// `Pool` is cheap to clone, clones share the same connections.
+/// Connections are returned to the pool when the `PooledConnection` is dropped.
+#[derive(Clone)]
+pub struct Pool {
+    shared: Arc<Shared>,
+}
+
+impl Pool {
+    /// Creates a new `PoolBuilder` for the specified database.
+    pub fn builder(database: &Database) -> PoolBuilder {
+        PoolBuilder::new(database)
+    }
+
+    /// Checks out a connection, opening a new one if none is idle and the pool is not full.
+    ///
+    /// Idle connections that fail the health check are closed and replaced.
+    /// Returns `Error::PoolTimeout` if no connection becomes available within the checkout timeout.
+    pub fn get(&self) -> error::Result<PooledConnection> {
+        let shared = &self.shared;
+        let deadline = Instant::now() + shared.config.checkout_timeout;
+        let mut state = shared.state();
+
+        loop {
+            if let Some(mut conn) = state.idle.pop() {
+                drop(state);
+                match shared.prepare(&mut conn) {
+                    Ok(()) => return Ok(self.wrap(conn)),
+                    Err(_) => {
+                        drop(conn);
+                        shared.close();
+                        // The replacement may fail the health check as well, so give up at the deadline.
+                        if Instant::now() >= deadline {
+                            return Err(error::Error::PoolTimeout(shared.config.checkout_timeout));
+                        }
+                        state = shared.state();
+                        continue;
+                    }
+                }
+            }
+
+            if state.size < shared.config.max_size {
+                state.size += 1;
+                drop(state);
+                let conn = Connection::new(&shared.database).and_then(|mut conn| {
+                    shared.prepare(&mut conn)?;
+                    Ok(conn)
+                });
+                return match conn {
+                    Ok(conn) => Ok(self.wrap(conn)),
+                    Err(e) => {
+                        shared.close();
+                        Err(e)
+                    }
+                };
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(error::Error::PoolTimeout(shared.config.checkout_timeout));
+            }
+            state = shared
+                .available
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    /// Returns the number of open connections, idle and checked out.
+    pub fn size(&self) -> usize {
+        self.shared.state().size
+    }
+
+    /// Returns the number of idle connections.
+    pub fn idle(&self) -> usize {
+        self.shared.state().idle.len()
+    }
+
+    fn wrap(&self, conn: Connection) -> PooledConnection {
+        PooledConnection {
+            conn: Some(conn),
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+/// A connection checked out from a `Pool`, returned to it on drop.
+///
+/// A connection with a transaction left open by `DropBehaviour::Ignore` is closed instead,
+/// so the next borrower doesn't inherit the transaction.
+pub struct PooledConnection {
+    conn: Option<Connection>,
+    shared: Arc<Shared>,
+}
+
+impl Deref for PooledConnection {
+    type Target = Connection;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.conn
+            .as_ref()
+            .expect("connection is only taken on drop")
+    }
+}
+
+impl DerefMut for PooledConnection {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.conn
+            .as_mut()
+            .expect("connection is only taken on drop")
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            if conn.has_ignored_transaction() {
+                // Dropping the connection closes the transaction and releases it from the statistics.
+                drop(conn);
+                self.shared.close();
+                return;
+            }
+            self.shared.state().idle.push(conn);
+            self.shared.available.notify_one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Pool, PooledConnection};
+    use crate::{
+        connection::{DropBehaviour, TransactionType},
+        error::Error,
+        test_utils::test_database,
+    };
+
+    fn assert_send<T: Send>() {}
+    fn assert_send_sync<T: Send + Sync + Clone>() {}
+
+    #[test]
+    fn test_pool_is_send_sync() {
+        assert_send_sync::<Pool>();
+        assert_send::<PooledConnection>();
+    }
+
+    #[test]
+    fn test_pool_checkout_and_return() {
+        let (_dir, db) = test_database();
+        let pool = Pool::builder(&db).with_min_size(2).build().unwrap();
+        assert_eq!((pool.size(), pool.idle()), (2, 2));
+
+        let conn = pool.get().unwrap();
+        conn.query("RETURN 1;").unwrap();
+        assert_eq!((pool.size(), pool.idle()), (2, 1));
+
+        drop(conn);
+        assert_eq!((pool.size(), pool.idle()), (2, 2));
+    }
+
+    #[test]
+    fn test_pool_exhausted() {
+        let (_dir, db) = test_database();
+        let pool = Pool::builder(&db)
+            .with_max_size(1)
+            .with_checkout_timeout(Duration::from_millis(10))
+            .build()
+            .unwrap();
+
+        let conn = pool.get().unwrap();
+        assert!(matches!(pool.get(), Err(Error::PoolTimeout(_))));
+        assert_eq!(pool.size(), 1);
+
+        drop(conn);
+        pool.get().unwrap();
+    }
+
+    #[test]
+    fn test_pool_invalid_size() {
+        let (_dir, db) = test_database();
+        let res = Pool::builder(&db).with_min_size(3).with_max_size(2).build();
+        assert!(matches!(
+            res,
+            Err(Error::InvalidPoolSize {
+                min_size: 3,
+                max_size: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_pool_closes_connection_with_ignored_transaction() {
+        let (_dir, db) = test_database();
+        let pool = Pool::builder(&db).with_min_size(1).build().unwrap();
+
+        let mut conn = pool.get().unwrap();
+        let id = conn.id();
+        let mut tx = conn.transaction(TransactionType::ReadWrite).unwrap();
+        tx.set_drop_behaviour(DropBehaviour::Ignore);
+        drop(tx);
+        drop(conn);
+
+        assert_eq!(db.transaction_stats().writer(), None);
+        assert_eq!((pool.size(), pool.idle()), (1, 1));
+        let mut conn = pool.get().unwrap();
+        assert_ne!(conn.id(), id);
+        conn.transaction(TransactionType::ReadWrite).unwrap();
+    }
+}
@@ -3,23 +3,45 @@ use crate::ffi::kuzu_prepared_statement;
 use crate::helper::{convert_inner_to_owned_string, CCow};
 use crate::ptrc::PtrContainer;
 use crate::query_result::QueryResult;
-use crate::types::value::KuzuValue;
+use crate::types::{logical_type::LogicalType, value::KuzuValue};
 use crate::{error, ffi, into_cstr};
+use std::cell::RefCell;
 use std::ffi::{CStr, CString};
+use std::time::Duration;
 
 /// Represents an argument for a prepared statement.
 pub struct Argument(CCow, KuzuValue);
 
+/// A trait for Rust types that can be bound as the named parameters of a prepared statement.
+/// Usually derived with `#[derive(IntoParams)]`, which binds each field to the parameter of the same name.
+pub trait IntoParams {
+    /// Returns the named parameters, as `(name, value)` pairs.
+    fn to_params(&self) -> Vec<(&'static str, KuzuValue)>;
+}
+
 /// Represents a prepared statement in Kuzu
 pub struct Statement<'conn> {
     /// The database connection associated with the statement.
     conn: &'conn connection::Connection,
+    /// The prepared query, only `None` once it has been put back into the statement cache.
+    prepared: Option<PreparedQuery>,
+    /// The query text the statement is cached under, if it was prepared with `prepare_cached`.
+    cache_key: Option<String>,
+    /// Arguments for the prepared statement.
+    args: Vec<Argument>,
+}
+
+/// A query prepared by Kuzu, which can be cached by the connection that prepared it.
+pub(crate) struct PreparedQuery {
     /// Pointer container for the underlying FFI prepared statement.
     stmt: PtrContainer<ffi::kuzu_prepared_statement>,
     /// CString representation of the statement.
     _stmt: CString,
-    /// Arguments for the prepared statement.
-    args: Vec<Argument>,
+    /// The names of the parameters of the statement, in order of appearance.
+    params: Vec<String>,
+    /// The parameters bound on the engine side, which keep their values across executions,
+    /// with the logical types of their values.
+    bound: RefCell<Vec<(CString, LogicalType)>>,
 }
 
 macro_rules! static_cstr {
@@ -49,10 +71,10 @@ static STMT_LOOKUP: [&CStr; 256] = static_cstr![
     250, 251, 252, 253, 254, 255
 ];
 
-impl<'conn> Statement<'conn> {
-    /// Creates a new instance of `Statement`.
+impl PreparedQuery {
+    /// Prepares the query on the connection.
     /// Returns an error if there is an issue preparing the statement or retrieving the result.
-    fn new(conn: &'conn Connection, query: &str) -> error::Result<Self> {
+    fn new(conn: &Connection, query: &str) -> error::Result<Self> {
         let cstring = into_cstr!(query)?;
         let stmt = unsafe { ffi::kuzu_connection_prepare(conn.to_inner(), cstring.as_ptr()) };
         if stmt.is_null() {
@@ -60,59 +82,224 @@ impl<'conn> Statement<'conn> {
                 *mut kuzu_prepared_statement,
             >()));
         }
-        let is_success = unsafe { ffi::kuzu_prepared_statement_is_success(stmt) };
+        let stmt = PtrContainer(stmt);
+        let is_success = unsafe { ffi::kuzu_prepared_statement_is_success(stmt.0) };
 
         if !is_success {
-            let raw_error_msg = unsafe { ffi::kuzu_prepared_statement_get_error_message(stmt) };
+            let raw_error_msg = unsafe { ffi::kuzu_prepared_statement_get_error_message(stmt.0) };
             let error_msg = convert_inner_to_owned_string(raw_error_msg)?;
-            return Err(error::Error::ConnectionError(error_msg));
+            return Err(error::Error::from_engine_message(error_msg));
         }
 
         let allow_active_transaction =
-            unsafe { ffi::kuzu_prepared_statement_allow_active_transaction(stmt) };
+            unsafe { ffi::kuzu_prepared_statement_allow_active_transaction(stmt.0) };
 
         if !allow_active_transaction {
             return Err(error::Error::TxNotAllowed);
         }
 
         Ok(Self {
-            conn,
-            stmt: PtrContainer::try_new(stmt)?,
+            stmt,
             _stmt: cstring,
+            params: parameter_names(query),
+            bound: RefCell::default(),
+        })
+    }
+
+    /// Binds NULL to the parameters bound on the engine side but not in `args`, so the values bound
+    /// before `clear_bindings`, or by a previous user of a cached statement, don't leak into the next execution.
+    ///
+    /// The C API can't unbind parameters, so this is the closest to a freshly prepared statement.
+    /// The NULL has the type of the value bound before, so it passes the type check of the engine.
+    fn reset_bindings(&self, args: &[Argument]) -> error::Result<()> {
+        let (kept, stale): (Vec<_>, Vec<_>) =
+            self.bound.borrow_mut().drain(..).partition(|(key, _)| {
+                args.iter()
+                    .any(|Argument(arg, _)| arg.as_c_str() == key.as_c_str())
+            });
+        *self.bound.borrow_mut() = kept;
+
+        for (key, logical_type) in stale {
+            let data_type = PtrContainer::try_from(&logical_type)?;
+            let null = PtrContainer::try_new(unsafe {
+                ffi::kuzu_value_create_null_with_data_type(data_type.0)
+            })?;
+            unsafe { ffi::kuzu_prepared_statement_bind_value(self.stmt.0, key.as_ptr(), null.0) };
+        }
+        Ok(())
+    }
+}
+
+impl<'conn> Statement<'conn> {
+    /// Creates a new instance of `Statement`.
+    /// Returns an error if there is an issue preparing the statement or retrieving the result.
+    fn new(conn: &'conn Connection, query: &str) -> error::Result<Self> {
+        Ok(Self {
+            conn,
+            prepared: Some(PreparedQuery::new(conn, query)?),
+            cache_key: None,
+            args: vec![],
+        })
+    }
+
+    /// Creates a new instance of `Statement`, reusing the prepared query cached by the connection if any.
+    fn new_cached(conn: &'conn Connection, query: &str) -> error::Result<Self> {
+        // The bindings of the previous user are reset by the next execution.
+        let cached = conn.statement_cache().take(query);
+        let prepared = match cached {
+            Some(prepared) => prepared,
+            None => PreparedQuery::new(conn, query)?,
+        };
+        Ok(Self {
+            conn,
+            prepared: Some(prepared),
+            cache_key: Some(query.to_owned()),
             args: vec![],
         })
     }
 
-    /// Binds a value to a parameter in the prepared statement.
+    /// Returns the prepared query of the statement.
+    fn prepared(&self) -> &PreparedQuery {
+        self.prepared
+            .as_ref()
+            .expect("the prepared query is only taken on drop")
+    }
+
+    /// Returns the pointer to the underlying FFI prepared statement.
+    fn to_inner(&self) -> *mut ffi::kuzu_prepared_statement {
+        self.prepared().stmt.0
+    }
+
+    /// Returns the names of the parameters of the statement, in order of appearance.
+    ///
+    /// The C API doesn't expose the parameters of a prepared statement, so they are scanned from the
+    /// query text on a best-effort basis, skipping string literals, escaped identifiers and comments.
+    pub fn parameters(&self) -> &[String] {
+        &self.prepared().params
+    }
+
+    /// Binds a value to a parameter in the prepared statement,
+    /// replacing the value already bound to the parameter if any.
     pub fn bind<K, V>(&mut self, k: K, v: V) -> error::Result<&mut Self>
     where
         (K, V): TryInto<Argument, Error = error::Error>,
     {
-        self.args.push((k, v).try_into()?);
+        let arg: Argument = (k, v).try_into()?;
+        let existing = self
+            .args
+            .iter_mut()
+            .find(|Argument(key, _)| key.as_c_str() == arg.0.as_c_str());
+        match existing {
+            Some(existing) => *existing = arg,
+            None => self.args.push(arg),
+        }
+        Ok(self)
+    }
+
+    /// Binds every named parameter of `params`, e.g. a struct deriving `IntoParams`.
+    pub fn bind_all<P: IntoParams + ?Sized>(&mut self, params: &P) -> error::Result<&mut Self> {
+        for (k, v) in params.to_params() {
+            self.bind(k, v)?;
+        }
         Ok(self)
     }
 
+    /// Removes the values bound to the parameters of the prepared statement,
+    /// which are NULL in the next execution unless bound again.
+    pub fn clear_bindings(&mut self) -> &mut Self {
+        self.args.clear();
+        self
+    }
+
+    /// Checks that exactly the `parameters` of the statement are bound.
+    /// Returns `Error::MissingParameter` or `Error::UnknownParameter` naming the first mismatch.
+    ///
+    /// The check is best-effort, as it relies on the parameters scanned from the query text.
+    /// `execute_with` runs it, while `execute` leaves it to the engine to validate the bound values.
+    pub fn check_bindings(&self) -> error::Result<()> {
+        let params = self.parameters();
+        let is_bound = |param: &str| {
+            self.args
+                .iter()
+                .any(|Argument(key, _)| key.as_c_str().to_bytes() == param.as_bytes())
+        };
+        if let Some(missing) = params.iter().find(|param| !is_bound(param)) {
+            return Err(error::Error::MissingParameter(missing.clone()));
+        }
+        for Argument(key, _) in &self.args {
+            let key = key.as_c_str().to_string_lossy();
+            if !params.iter().any(|param| *param == key) {
+                return Err(error::Error::UnknownParameter(key.into_owned()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes the prepared statement with a fresh set of parameters,
+    /// replacing the values bound before.
+    ///
+    /// Returns `Error::MissingParameter` or `Error::UnknownParameter` if the parameters don't match
+    /// the ones of the statement, see `check_bindings`, before the statement reaches the engine.
+    /// Returns an error if there is an issue executing the statement or retrieving the result.
+    pub fn execute_with<I, K, V>(&mut self, params: I) -> error::Result<QueryResult>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        (K, V): TryInto<Argument, Error = error::Error>,
+    {
+        self.clear_bindings();
+        for (k, v) in params {
+            self.bind(k, v)?;
+        }
+        self.check_bindings()?;
+        self.execute()
+    }
+
     /// Executes the prepared statement.
     /// Returns an error if there is an issue executing the statement or retrieving the result.
+    ///
+    /// Parameters bound before but not anymore, e.g. after `clear_bindings`, are NULL.
+    /// Call `check_bindings` beforehand to catch parameters that are not bound.
     pub fn execute(&self) -> error::Result<QueryResult> {
+        self.prepared().reset_bindings(&self.args)?;
         self.args.iter().try_for_each(|arg| {
             let Argument(_key, _val) = arg;
 
-            let key = match _key {
-                CCow::Owned(key_name) => key_name.as_ptr(),
-                CCow::Static(key_name) => key_name.as_ptr(),
-            };
+            let key = _key.as_c_str().as_ptr();
 
             let val = PtrContainer::try_from(_val)?;
+            let logical_type = LogicalType::try_from(PtrContainer::try_new(unsafe {
+                ffi::kuzu_value_get_data_type(val.0)
+            })?)?;
 
             unsafe {
-                ffi::kuzu_prepared_statement_bind_value(self.stmt.0, key, val.0);
+                ffi::kuzu_prepared_statement_bind_value(self.to_inner(), key, val.0);
             };
-            Ok(())
+            let mut bound = self.prepared().bound.borrow_mut();
+            match bound
+                .iter_mut()
+                .find(|(bound, _)| bound.as_c_str() == _key.as_c_str())
+            {
+                Some((_, bound_type)) => *bound_type = logical_type,
+                None => bound.push((_key.as_c_str().to_owned(), logical_type)),
+            }
+            Ok::<_, error::Error>(())
         })?;
 
-        let raw_result = unsafe { ffi::kuzu_connection_execute(self.conn.to_inner(), self.stmt.0) };
-        PtrContainer::try_new(raw_result)?.try_into()
+        self.conn.run_query(|| {
+            let raw_result =
+                unsafe { ffi::kuzu_connection_execute(self.conn.to_inner(), self.to_inner()) };
+            PtrContainer::try_new(raw_result)?.try_into()
+        })
+    }
+
+    /// Executes the prepared statement with a timeout that overrides the query timeout of the connection.
+    /// Returns `Error::Timeout` if the statement runs longer than `timeout`.
+    pub fn execute_with_timeout(&self, timeout: Duration) -> error::Result<QueryResult> {
+        let previous = self.conn.query_timeout().unwrap_or_default();
+        self.conn.apply_query_timeout(timeout);
+        let res = self.execute();
+        self.conn.apply_query_timeout(previous);
+        res
     }
 }
 
@@ -123,9 +310,100 @@ impl Connection {
         let query = query.as_ref();
         Statement::new(self, query)
     }
+
+    /// Prepares a Kuzu query statement for execution, reusing the statement cached for the same query text.
+    ///
+    /// The prepared query is put back into the least recently used statement cache of the connection
+    /// when the `Statement` is dropped. A cached statement starts without any bindings.
+    /// Returns an error if there is an issue preparing the statement or retrieving the result.
+    pub fn prepare_cached<S: AsRef<str>>(&mut self, query: S) -> error::Result<Statement<'_>> {
+        Statement::new_cached(self, query.as_ref())
+    }
+
+    /// Sets the maximum number of prepared statements cached by the connection,
+    /// evicting the least recently used ones. A capacity of `0` disables the cache.
+    /// Default value is 64.
+    pub fn set_statement_cache_capacity(&mut self, capacity: usize) {
+        self.statement_cache().set_capacity(capacity)
+    }
+
+    /// Returns the maximum number of prepared statements cached by the connection.
+    pub fn statement_cache_capacity(&self) -> usize {
+        self.statement_cache().capacity()
+    }
+
+    /// Removes all the prepared statements cached by the connection,
+    /// e.g. after a schema change made them outdated.
+    pub fn flush_statement_cache(&mut self) {
+        self.statement_cache().clear()
+    }
+}
+
+impl Drop for Statement<'_> {
+    fn drop(&mut self) {
+        if let (Some(query), Some(prepared)) = (self.cache_key.take(), self.prepared.take()) {
+            self.conn.statement_cache().insert(query, prepared);
+        }
+    }
 }
 
-impl<V: Into<KuzuValue>> TryFrom<(usize, V)> for Argument {
+/// Returns the names of the `$` parameters of a Cypher query, in order of appearance.
+/// String literals, escaped identifiers and comments are skipped, as far as a scanner can tell them apart.
+fn parameter_names(query: &str) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    let mut chars = query.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' | '`' => {
+                while let Some(next) = chars.next() {
+                    match next {
+                        '\\' if c != '`' => {
+                            chars.next();
+                        }
+                        _ if next == c => break,
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                chars.by_ref().find(|&next| next == '\n');
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for next in chars.by_ref() {
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            '$' => {
+                let mut name = String::new();
+                while let Some(&next) = chars.peek() {
+                    if !(next.is_alphanumeric() || next == '_') {
+                        break;
+                    }
+                    name.push(next);
+                    chars.next();
+                }
+                if !name.is_empty() && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    names
+}
+
+impl<V> TryFrom<(usize, V)> for Argument
+where
+    V: TryInto<KuzuValue>,
+    error::Error: From<V::Error>,
+{
     type Error = error::Error;
     fn try_from(value: (usize, V)) -> Result<Self, Self::Error> {
         let (idx, val) = value;
@@ -139,16 +417,170 @@ impl<V: Into<KuzuValue>> TryFrom<(usize, V)> for Argument {
                 CCow::Owned(cstr_idx)
             }
         };
-        Ok(Argument(name, val.into()))
+        Ok(Argument(name, val.try_into()?))
     }
 }
 
-impl<V: Into<KuzuValue>> TryFrom<(&str, V)> for Argument {
+impl<V> TryFrom<(&str, V)> for Argument
+where
+    V: TryInto<KuzuValue>,
+    error::Error: From<V::Error>,
+{
     type Error = error::Error;
     fn try_from(value: (&str, V)) -> Result<Self, Self::Error> {
         let (_name, val) = value;
 
         let name = into_cstr!(_name)?;
-        Ok(Argument(CCow::Owned(name), val.into()))
+        Ok(Argument(CCow::Owned(name), val.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parameter_names;
+    use crate::{
+        connection::Connection, error::Error, query_result::QueryResult, test_utils::test_database,
+        types::row::Row,
+    };
+
+    /// Returns the `a` and `b` columns of the first row of a query result.
+    fn first_row(result: QueryResult) -> (Option<i64>, Option<i64>) {
+        let row = result.iter::<Row>().unwrap().next().unwrap().unwrap();
+        (
+            row.get_val_by_column("a").unwrap(),
+            row.get_val_by_column("b").unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_cached_statement_starts_without_bindings() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+        let query = "RETURN $a AS a, $b AS b;";
+
+        {
+            let mut stmt = conn.prepare_cached(query).unwrap();
+            stmt.bind("a", 1i64).unwrap().bind("b", 2i64).unwrap();
+            assert_eq!(first_row(stmt.execute().unwrap()), (Some(1), Some(2)));
+        }
+
+        let mut stmt = conn.prepare_cached(query).unwrap();
+        stmt.bind("a", 3i64).unwrap();
+        assert_eq!(first_row(stmt.execute().unwrap()), (Some(3), None));
+    }
+
+    #[test]
+    fn test_bind_replaces_value() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+
+        let mut stmt = conn.prepare("RETURN $a AS a, $b AS b;").unwrap();
+        stmt.bind("a", 1i64)
+            .unwrap()
+            .bind("b", 2i64)
+            .unwrap()
+            .bind("a", 3i64)
+            .unwrap();
+        assert_eq!(first_row(stmt.execute().unwrap()), (Some(3), Some(2)));
+    }
+
+    #[test]
+    fn test_clear_bindings() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+
+        let mut stmt = conn.prepare("RETURN $a AS a, $b AS b;").unwrap();
+        stmt.bind("a", 1i64).unwrap().bind("b", 2i64).unwrap();
+        assert_eq!(first_row(stmt.execute().unwrap()), (Some(1), Some(2)));
+
+        stmt.clear_bindings().bind("a", 3i64).unwrap();
+        assert_eq!(first_row(stmt.execute().unwrap()), (Some(3), None));
+    }
+
+    #[test]
+    fn test_execute_with() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+
+        let mut stmt = conn.prepare("RETURN $a AS a, $b AS b;").unwrap();
+        let result = stmt.execute_with([("a", 1i64), ("b", 2i64)]).unwrap();
+        assert_eq!(first_row(result), (Some(1), Some(2)));
+
+        let result = stmt.execute_with([("b", 4i64), ("a", 3i64)]).unwrap();
+        assert_eq!(first_row(result), (Some(3), Some(4)));
+
+        assert!(matches!(
+            stmt.execute_with([("a", 1i64)]),
+            Err(Error::MissingParameter(param)) if param == "b"
+        ));
+        assert!(matches!(
+            stmt.execute_with([("a", 1i64), ("b", 2i64), ("c", 3i64)]),
+            Err(Error::UnknownParameter(param)) if param == "c"
+        ));
+    }
+
+    #[test]
+    fn test_statement_cache_eviction_and_flush() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+        conn.set_statement_cache_capacity(2);
+
+        for query in ["RETURN 1;", "RETURN 2;", "RETURN 1;", "RETURN 3;"] {
+            conn.prepare_cached(query).unwrap().execute().unwrap();
+        }
+        // "RETURN 2;" is the least recently used statement, so it was evicted for "RETURN 3;".
+        assert!(conn.statement_cache().take("RETURN 2;").is_none());
+        for query in ["RETURN 1;", "RETURN 3;"] {
+            let stmt = conn.statement_cache().take(query).unwrap();
+            conn.statement_cache().insert(query.to_owned(), stmt);
+        }
+
+        conn.flush_statement_cache();
+        assert!(conn.statement_cache().take("RETURN 1;").is_none());
+        assert!(conn.statement_cache().take("RETURN 3;").is_none());
+    }
+
+    #[test]
+    fn test_parameter_names() {
+        assert_eq!(
+            parameter_names("MATCH (u:User) WHERE u.age > $age AND u.name = $name RETURN $age;"),
+            vec!["age", "name"]
+        );
+        assert_eq!(
+            parameter_names(
+                "MATCH (u) // not $a\nWHERE u.name = '$b \\' $c' /* $d */ AND u.`$e` = $f_1 RETURN u;"
+            ),
+            vec!["f_1"]
+        );
+        assert_eq!(parameter_names("RETURN $0 + $1;"), vec!["0", "1"]);
+        assert!(parameter_names("RETURN 1;").is_empty());
+    }
+
+    #[test]
+    fn test_parameter_names_in_escaped_identifiers() {
+        assert_eq!(
+            parameter_names("MATCH (u:`$User`) RETURN u.`$name`, u.`a``$b`, $c;"),
+            vec!["c"]
+        );
+    }
+
+    #[test]
+    fn test_parameter_names_in_comments() {
+        assert_eq!(
+            parameter_names("RETURN /* $a\n $b */ $c // $d\n;"),
+            vec!["c"]
+        );
+        assert_eq!(parameter_names("RETURN $a /* unterminated $b"), vec!["a"]);
+        assert_eq!(parameter_names("RETURN $a / $b;"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_parameter_names_around_escaped_quotes() {
+        assert_eq!(
+            parameter_names(r#"RETURN 'it\'s $a', "say \"$b\"", $c;"#),
+            vec!["c"]
+        );
+        assert_eq!(parameter_names(r"RETURN '\\', $a;"), vec!["a"]);
+        assert_eq!(parameter_names(r#"RETURN "'$a'", '"$b"', $c;"#), vec!["c"]);
     }
 }
@@ -1,4 +1,4 @@
-use crate::error;
+use crate::{error, ffi};
 /// Wrapper for a raw pointer, providing validation functionality.
 ///
 /// The `PtrContainer` struct wraps a raw pointer `*mut T` and provides a `validate` method
@@ -60,7 +60,21 @@ drop_ptr_container!(kuzu_connection, kuzu_connection_destroy);
 drop_ptr_container!(kuzu_node_val, kuzu_node_val_destroy);
 drop_ptr_container!(kuzu_prepared_statement, kuzu_prepared_statement_destroy);
 drop_ptr_container!(kuzu_query_result, kuzu_query_result_destroy);
+drop_ptr_container!(kuzu_flat_tuple, kuzu_flat_tuple_destroy);
+drop_ptr_container!(kuzu_query_summary, kuzu_query_summary_destroy);
 drop_ptr_container!(kuzu_rel_val, kuzu_rel_val_destroy);
 drop_ptr_container!(kuzu_value, kuzu_value_destroy);
 drop_ptr_container!(kuzu_database, kuzu_database_destroy);
 drop_ptr_container!(kuzu_logical_type, kuzu_data_type_destroy);
+
+// SAFETY: Kuzu documents `kuzu_connection` as "thread-safe. Multiple connections can connect to the
+// same `Database` instance in a multi-threaded environment", so a database is shared between threads
+// with each of them opening its own connections, and is destroyed from whichever thread drops it last.
+unsafe impl Send for PtrContainer<ffi::kuzu_database> {}
+unsafe impl Sync for PtrContainer<ffi::kuzu_database> {}
+// SAFETY: per the same guarantee, a connection can be used from any thread. It is not `Sync`,
+// as `Connection` keeps per-query state, but the connection itself can move between threads.
+unsafe impl Send for PtrContainer<ffi::kuzu_connection> {}
+// SAFETY: prepared statements are only used through the connection that owns them,
+// so they move to another thread together with it.
+unsafe impl Send for PtrContainer<ffi::kuzu_prepared_statement> {}
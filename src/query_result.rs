@@ -1,4 +1,4 @@
-use std::{collections::HashMap, marker::PhantomData, rc::Rc};
+use std::{collections::HashMap, marker::PhantomData, sync::Arc, time::Duration};
 
 use crate::{
     connection::Connection,
@@ -6,7 +6,11 @@ use crate::{
     helper::convert_inner_to_owned_string,
     into_cstr,
     ptrc::PtrContainer,
-    types::{row::Row, value::KuzuValue},
+    types::{
+        logical_type::LogicalType,
+        row::{column_name, Row},
+        value::KuzuValue,
+    },
 };
 
 use crate::ffi;
@@ -25,7 +29,7 @@ impl TryFrom<PtrContainer<ffi::kuzu_query_result>> for QueryResult {
             let s = convert_inner_to_owned_string(unsafe {
                 ffi::kuzu_query_result_get_error_message(value.0)
             })?;
-            return Err(error::Error::QueryResultError(s));
+            return Err(error::Error::from_engine_message(s));
         }
 
         Ok(Self(value))
@@ -33,69 +37,160 @@ impl TryFrom<PtrContainer<ffi::kuzu_query_result>> for QueryResult {
 }
 
 impl QueryResult {
-    /// Returns an iterator over the rows of the query result.
-    ///
-    /// Each iteration produces a `TryFrom<Row>` object, which represents a single row of the result set.
+    /// Returns the columns of the query result as `(name, logical type)` pairs, ordered by their index.
     ///
-    /// Returns an error if there is an issue retrieving the rows from the query result.
-    pub fn iter<R: TryFrom<Row>>(self) -> error::Result<Iter<R>> {
-        let len = unsafe { ffi::kuzu_query_result_get_num_tuples(self.0 .0) } as usize;
-        let column_len = unsafe { ffi::kuzu_query_result_get_num_columns(self.0 .0) };
+    /// Returns an error if there is an issue retrieving the column names or types from the query result.
+    pub fn columns(&self) -> error::Result<Vec<(String, LogicalType)>> {
+        self.column_names()?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                let logical_type = PtrContainer::try_new(unsafe {
+                    ffi::kuzu_query_result_get_column_data_type(self.0 .0, idx as u64)
+                })?;
+                Ok((name, logical_type.try_into()?))
+            })
+            .collect()
+    }
+
+    /// Returns the compiling and execution times of the query.
+    pub fn summary(&self) -> error::Result<QuerySummary> {
+        let summary =
+            PtrContainer::try_new(unsafe { ffi::kuzu_query_result_get_query_summary(self.0 .0) })?;
+        Ok(summary.into())
+    }
+
+    /// Returns the number of columns in the query result.
+    pub fn num_columns(&self) -> usize {
+        unsafe { ffi::kuzu_query_result_get_num_columns(self.0 .0) as usize }
+    }
 
-        let columns = (0..column_len)
+    /// Returns the column names of the query result, ordered by their index.
+    fn column_names(&self) -> error::Result<Vec<String>> {
+        (0..self.num_columns())
             .map(|idx| {
                 convert_inner_to_owned_string(unsafe {
-                    ffi::kuzu_query_result_get_column_name(self.0 .0, idx)
+                    ffi::kuzu_query_result_get_column_name(self.0 .0, idx as u64)
                 })
-                .map(|res| (res, idx as usize))
             })
-            .collect::<Result<_, _>>()?;
+            .collect()
+    }
+
+    /// Returns an iterator over the rows of the query result.
+    ///
+    /// Each iteration produces an `error::Result<R>`, where `R: TryFrom<Row>` represents a single row of the result set.
+    /// A row that fails to decode yields an `Error::RowError` carrying the index of the row, instead of ending the iteration.
+    /// A row that can't be read from the query result yields an `Error::RowError` and ends the iteration.
+    ///
+    /// Returns an error if there is an issue retrieving the column names from the query result.
+    pub fn iter<R: TryFrom<Row>>(self) -> error::Result<Iter<R>> {
+        let len = unsafe { ffi::kuzu_query_result_get_num_tuples(self.0 .0) } as usize;
+        let columns = self
+            .column_names()?
+            .into_iter()
+            .enumerate()
+            .map(|(idx, name)| (name, idx))
+            .collect();
 
         Ok(Iter {
             _m: PhantomData,
             inner: self,
-            columns: Rc::new(columns),
+            columns: Arc::new(columns),
             len,
+            row_idx: 0,
+            finished: false,
         })
     }
 }
 
+/// Represents the timings of a query execution in Kuzu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuerySummary {
+    /// The time spent compiling the query.
+    pub compiling_time: Duration,
+    /// The time spent executing the query.
+    pub execution_time: Duration,
+}
+
+impl From<PtrContainer<ffi::kuzu_query_summary>> for QuerySummary {
+    fn from(value: PtrContainer<ffi::kuzu_query_summary>) -> Self {
+        // Kuzu reports both timings in milliseconds.
+        let compiling_time = unsafe { ffi::kuzu_query_summary_get_compiling_time(value.0) };
+        let execution_time = unsafe { ffi::kuzu_query_summary_get_execution_time(value.0) };
+
+        Self {
+            compiling_time: Duration::from_secs_f64(compiling_time.max(0.0) / 1000.0),
+            execution_time: Duration::from_secs_f64(execution_time.max(0.0) / 1000.0),
+        }
+    }
+}
+
 /// Iterator over the rows of a query result.
 pub struct Iter<R: TryFrom<Row>> {
     inner: QueryResult,
-    columns: Rc<HashMap<String, usize>>,
+    columns: Arc<HashMap<String, usize>>,
     len: usize,
+    /// The index of the next row to be read.
+    row_idx: usize,
+    /// Whether reading a row failed, which ends the iteration.
+    finished: bool,
     _m: PhantomData<R>,
 }
 
+impl<R> Iter<R>
+where
+    R: TryFrom<Row>,
+    error::Error: From<R::Error>,
+{
+    /// Decodes the values of the tuple into a `R`.
+    fn decode_row(&self, tuple: PtrContainer<ffi::kuzu_flat_tuple>) -> error::Result<R> {
+        let values: Vec<KuzuValue> = (0..self.columns.len())
+            .map(|idx| {
+                PtrContainer::try_new(unsafe {
+                    ffi::kuzu_flat_tuple_get_value(tuple.0, idx as u64)
+                })
+                .and_then(KuzuValue::try_from)
+                .map_err(|e| error::Error::ColumnDecodeError {
+                    column: column_name(&self.columns, idx),
+                    source: Box::new(e),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let row = Row::new(Arc::clone(&self.columns), values);
+        Ok(R::try_from(row)?)
+    }
+}
+
 impl<R> Iterator for Iter<R>
 where
     R: TryFrom<Row>,
+    error::Error: From<R::Error>,
 {
-    type Item = R;
+    type Item = error::Result<R>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let has_next = unsafe { ffi::kuzu_query_result_has_next(self.inner.0 .0) };
-        if !has_next {
+        if self.finished || !unsafe { ffi::kuzu_query_result_has_next(self.inner.0 .0) } {
             return None;
         }
 
-        let _row = unsafe { ffi::kuzu_query_result_get_next(self.inner.0 .0) };
-        if _row.is_null() {
-            return None;
-        }
+        let row = self.row_idx;
+        self.row_idx += 1;
+        self.len = self.len.saturating_sub(1);
 
-        let values: Vec<KuzuValue> = (0..self.columns.len())
-            .map(|idx| {
-                let inner = unsafe { ffi::kuzu_flat_tuple_get_value(_row, idx as u64) };
-                PtrContainer::try_new(inner)?.try_into()
-            })
-            .collect::<Result<_, _>>()
-            .ok()?;
+        // A row that can't be read ends the iteration, as the following calls would fail the same way.
+        let res =
+            PtrContainer::try_new(unsafe { ffi::kuzu_query_result_get_next(self.inner.0 .0) })
+                .inspect_err(|_| {
+                    self.finished = true;
+                    self.len = 0;
+                })
+                .and_then(|tuple| self.decode_row(tuple));
 
-        let row = Row::new(Rc::clone(&self.columns), values);
-        self.len -= 1;
-        Self::Item::try_from(row).ok()
+        Some(res.map_err(|e| error::Error::RowError {
+            row,
+            source: Box::new(e),
+        }))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -108,7 +203,58 @@ impl Connection {
     /// Returns an error if there is an issue executing the query or retrieving the query result.
     pub fn query<S: AsRef<str>>(&self, query: S) -> error::Result<QueryResult> {
         let cst = into_cstr!(query.as_ref())?;
-        let raw_result = unsafe { ffi::kuzu_connection_query(self.to_inner(), cst.as_ptr()) };
-        PtrContainer::try_new(raw_result)?.try_into()
+        self.run_query(|| {
+            let raw_result = unsafe { ffi::kuzu_connection_query(self.to_inner(), cst.as_ptr()) };
+            PtrContainer::try_new(raw_result)?.try_into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        connection::Connection, test_utils::test_database, types::logical_type::LogicalType,
+    };
+
+    #[test]
+    fn test_columns() {
+        let (_dir, db) = test_database();
+        let conn = Connection::new(&db).unwrap();
+        conn.query("CREATE NODE TABLE Person(name STRING, age INT64, scores DOUBLE[], PRIMARY KEY (name));")
+            .unwrap();
+
+        let result = conn
+            .query("MATCH (p:Person) RETURN p.name, p.age AS age, p.scores, p;")
+            .unwrap();
+        assert_eq!(result.num_columns(), 4);
+        assert_eq!(
+            result.columns().unwrap(),
+            vec![
+                ("p.name".to_owned(), LogicalType::String),
+                ("age".to_owned(), LogicalType::Int64),
+                (
+                    "p.scores".to_owned(),
+                    LogicalType::VarList(Box::new(LogicalType::Double))
+                ),
+                ("p".to_owned(), LogicalType::Node),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let (_dir, db) = test_database();
+        let conn = Connection::new(&db).unwrap();
+        conn.query("CREATE NODE TABLE Person(name STRING, PRIMARY KEY (name));")
+            .unwrap();
+
+        let result = conn.query("MATCH (p:Person) RETURN p.name;").unwrap();
+        let summary = result.summary().unwrap();
+        // Both timings are reported in milliseconds, so a trivial query takes well below a minute.
+        assert!(summary.compiling_time < Duration::from_secs(60));
+        assert!(summary.execution_time < Duration::from_secs(60));
+        assert_eq!(summary, result.summary().unwrap());
     }
 }
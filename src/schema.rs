@@ -0,0 +1,261 @@
+use crate::{
+    catalog::{Catalog, Property},
+    connection::Connection,
+    error,
+};
+
+/// The kind of a table of the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableKind {
+    /// A node table.
+    Node,
+    /// A rel table.
+    Rel,
+}
+
+/// A trait for Rust types describing a node or rel table.
+///
+/// Usually derived with `#[derive(KuzuNodeTable)]` or `#[derive(KuzuRelTable)]`.
+pub trait KuzuTable {
+    /// The name of the table.
+    const NAME: &'static str;
+    /// The kind of the table.
+    const KIND: TableKind;
+
+    /// Returns the `CREATE NODE TABLE` or `CREATE REL TABLE` statement of the table.
+    fn create_statement() -> String;
+
+    /// Returns the properties of the table, in declaration order.
+    fn properties() -> Vec<Property>;
+
+    /// Returns the name of the primary key property of a node table.
+    fn primary_key() -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the names of the node tables the rels of a rel table start from and end at.
+    fn endpoints() -> Option<(&'static str, &'static str)> {
+        None
+    }
+}
+
+/// Quotes a table or property name with backticks, so it can be any identifier in a statement.
+#[doc(hidden)]
+pub fn quote_identifier(name: &str) -> String {
+    format!("`{}`", name.replace('`', "``"))
+}
+
+impl Connection {
+    /// Creates the table of `T` unless the database already has a table of the same name.
+    /// Returns whether the table was created.
+    ///
+    /// Kuzu has no `CREATE TABLE IF NOT EXISTS`, so the catalog is looked up first. Table names are
+    /// compared case-insensitively like Kuzu does, and an existing table whose kind, properties,
+    /// primary key or node tables differ from `T` is a [`TableSchemaMismatch`](error::Error::TableSchemaMismatch).
+    pub fn create_table<T: KuzuTable>(&self) -> error::Result<bool> {
+        if has_table::<T>(&self.catalog()?)? {
+            return Ok(false);
+        }
+        self.query(T::create_statement())?;
+        Ok(true)
+    }
+}
+
+/// Returns whether `catalog` has the table of `T`, or an error if the table differs from `T`.
+fn has_table<T: KuzuTable>(catalog: &Catalog) -> error::Result<bool> {
+    let node_table = catalog
+        .node_tables
+        .iter()
+        .find(|table| table.name.eq_ignore_ascii_case(T::NAME));
+    let rel_table = catalog
+        .rel_tables
+        .iter()
+        .find(|table| table.name.eq_ignore_ascii_case(T::NAME));
+
+    let matches = match (T::KIND, node_table, rel_table) {
+        (_, None, None) => return Ok(false),
+        (TableKind::Node, Some(table), _) => {
+            table.properties == T::properties()
+                && T::primary_key() == Some(table.primary_key.as_str())
+        }
+        (TableKind::Rel, _, Some(table)) => {
+            table.properties == T::properties()
+                && T::endpoints() == Some((table.src.as_str(), table.dst.as_str()))
+        }
+        _ => false,
+    };
+
+    if matches {
+        Ok(true)
+    } else {
+        Err(error::Error::TableSchemaMismatch(T::NAME.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_table, quote_identifier, KuzuTable, TableKind};
+    use crate::{
+        catalog::{Catalog, NodeTable, Property, RelTable},
+        connection::Connection,
+        error::Error,
+        test_utils::test_database,
+        types::logical_type::LogicalType,
+    };
+
+    fn property(name: &str, logical_type: LogicalType) -> Property {
+        Property {
+            name: name.to_owned(),
+            logical_type,
+        }
+    }
+
+    struct Person;
+
+    impl KuzuTable for Person {
+        const NAME: &'static str = "Person";
+        const KIND: TableKind = TableKind::Node;
+
+        fn create_statement() -> String {
+            "CREATE NODE TABLE `Person`(`name` STRING, PRIMARY KEY (`name`));".to_owned()
+        }
+
+        fn properties() -> Vec<Property> {
+            vec![property("name", LogicalType::String)]
+        }
+
+        fn primary_key() -> Option<&'static str> {
+            Some("name")
+        }
+    }
+
+    struct LowercasePerson;
+
+    impl KuzuTable for LowercasePerson {
+        const NAME: &'static str = "person";
+        const KIND: TableKind = TableKind::Node;
+
+        fn create_statement() -> String {
+            "CREATE NODE TABLE `person`(`id` INT64, PRIMARY KEY (`id`));".to_owned()
+        }
+
+        fn properties() -> Vec<Property> {
+            vec![property("id", LogicalType::Int64)]
+        }
+
+        fn primary_key() -> Option<&'static str> {
+            Some("id")
+        }
+    }
+
+    struct Knows;
+
+    impl KuzuTable for Knows {
+        const NAME: &'static str = "Knows";
+        const KIND: TableKind = TableKind::Rel;
+
+        fn create_statement() -> String {
+            "CREATE REL TABLE `Knows`(FROM `Person` TO `Person`, `since` INT64);".to_owned()
+        }
+
+        fn properties() -> Vec<Property> {
+            vec![property("since", LogicalType::Int64)]
+        }
+
+        fn endpoints() -> Option<(&'static str, &'static str)> {
+            Some(("Person", "Person"))
+        }
+    }
+
+    fn person_catalog(properties: Vec<Property>, primary_key: &str, dst: &str) -> Catalog {
+        Catalog {
+            node_tables: vec![NodeTable {
+                name: "Person".to_owned(),
+                properties,
+                primary_key: primary_key.to_owned(),
+            }],
+            rel_tables: vec![RelTable {
+                name: "Knows".to_owned(),
+                src: "Person".to_owned(),
+                dst: dst.to_owned(),
+                properties: vec![property("since", LogicalType::Int64)],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_quote_identifier() {
+        assert_eq!(quote_identifier("Person"), "`Person`");
+        assert_eq!(quote_identifier("first name"), "`first name`");
+        assert_eq!(quote_identifier("a`b"), "`a``b`");
+    }
+
+    #[test]
+    fn test_has_table() {
+        let empty = Catalog {
+            node_tables: vec![],
+            rel_tables: vec![],
+        };
+        assert!(!has_table::<Person>(&empty).unwrap());
+        assert!(!has_table::<Knows>(&empty).unwrap());
+
+        let catalog = person_catalog(
+            vec![property("name", LogicalType::String)],
+            "name",
+            "Person",
+        );
+        assert!(has_table::<Person>(&catalog).unwrap());
+        assert!(has_table::<Knows>(&catalog).unwrap());
+        assert!(matches!(
+            has_table::<LowercasePerson>(&catalog),
+            Err(Error::TableSchemaMismatch(name)) if name == "person"
+        ));
+
+        let with_age = person_catalog(
+            vec![
+                property("name", LogicalType::String),
+                property("age", LogicalType::Int64),
+            ],
+            "name",
+            "City",
+        );
+        assert!(matches!(
+            has_table::<Person>(&with_age),
+            Err(Error::TableSchemaMismatch(_))
+        ));
+        assert!(matches!(
+            has_table::<Knows>(&with_age),
+            Err(Error::TableSchemaMismatch(_))
+        ));
+
+        let other_kind = Catalog {
+            node_tables: vec![],
+            rel_tables: vec![RelTable {
+                name: "PERSON".to_owned(),
+                src: "Person".to_owned(),
+                dst: "Person".to_owned(),
+                properties: vec![],
+            }],
+        };
+        assert!(matches!(
+            has_table::<Person>(&other_kind),
+            Err(Error::TableSchemaMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_table() {
+        let (_dir, db) = test_database();
+        let conn = Connection::new(&db).unwrap();
+
+        assert!(conn.create_table::<Person>().unwrap());
+        assert!(!conn.create_table::<Person>().unwrap());
+        assert!(conn.create_table::<Knows>().unwrap());
+        assert!(!conn.create_table::<Knows>().unwrap());
+        assert!(matches!(
+            conn.create_table::<LowercasePerson>(),
+            Err(Error::TableSchemaMismatch(_))
+        ));
+        assert_eq!(conn.node_table_names().unwrap(), ["Person"]);
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::{BTreeMap, HashMap};
+
+/// The default number of prepared statements cached by a connection.
+pub(crate) const DEFAULT_CAPACITY: usize = 64;
+
+/// A least recently used cache of prepared statements, keyed by query text.
+///
+/// Statements are taken out of the cache while in use and put back afterwards,
+/// so a statement is never shared by two `Statement`s at the same time.
+pub(crate) struct StatementCache<V> {
+    /// The maximum number of cached statements, `0` disables the cache.
+    capacity: usize,
+    /// The cached statements with the tick they were last used at, by query text.
+    entries: HashMap<String, (u64, V)>,
+    /// The query texts of the cached statements by the tick they were last used at,
+    /// from the least to the most recently used.
+    recency: BTreeMap<u64, String>,
+    /// The tick of the most recently used statement.
+    tick: u64,
+}
+
+impl<V> StatementCache<V> {
+    /// Creates a new empty cache holding at most `capacity` statements.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::with_capacity(capacity),
+            recency: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// Returns the maximum number of cached statements.
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximum number of cached statements, evicting the least recently used ones.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict();
+    }
+
+    /// Takes the statement of the query out of the cache, if it is cached.
+    pub(crate) fn take(&mut self, query: &str) -> Option<V> {
+        let (tick, stmt) = self.entries.remove(query)?;
+        self.recency.remove(&tick);
+        Some(stmt)
+    }
+
+    /// Puts the statement of the query into the cache as the most recently used one,
+    /// replacing any statement already cached for the query.
+    pub(crate) fn insert(&mut self, query: String, stmt: V) {
+        self.take(&query);
+        self.tick += 1;
+        self.recency.insert(self.tick, query.clone());
+        self.entries.insert(query, (self.tick, stmt));
+        self.evict();
+    }
+
+    /// Removes all the cached statements.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Evicts the least recently used statements until the cache fits its capacity.
+    fn evict(&mut self) {
+        while self.entries.len() > self.capacity {
+            let Some((_, query)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&query);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StatementCache;
+
+    #[test]
+    fn test_statement_cache_lru() {
+        let mut cache = StatementCache::new(2);
+        cache.insert("a".to_owned(), 1);
+        cache.insert("b".to_owned(), 2);
+
+        // Using "a" makes "b" the least recently used statement.
+        let a = cache.take("a").unwrap();
+        assert_eq!(cache.take("a"), None);
+        cache.insert("a".to_owned(), a);
+        cache.insert("c".to_owned(), 3);
+
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.recency.len(), 2);
+        assert_eq!(cache.take("b"), None);
+        assert_eq!(cache.take("a"), Some(1));
+
+        cache.insert("a".to_owned(), 4);
+        cache.set_capacity(1);
+        assert_eq!(cache.take("c"), None);
+        assert_eq!(cache.take("a"), Some(4));
+
+        cache.insert("d".to_owned(), 5);
+        cache.clear();
+        assert!(cache.entries.is_empty());
+        assert!(cache.recency.is_empty());
+
+        cache.set_capacity(0);
+        cache.insert("e".to_owned(), 6);
+        assert_eq!(cache.take("e"), None);
+    }
+}
@@ -0,0 +1,12 @@
+use tempfile::TempDir;
+
+use crate::database::Database;
+
+/// Creates a database in a temporary directory, which is removed once the returned `TempDir` is dropped.
+pub(crate) fn test_database() -> (TempDir, Database) {
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::builder(dir.path().to_str().unwrap())
+        .build()
+        .unwrap();
+    (dir, db)
+}
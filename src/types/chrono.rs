@@ -0,0 +1,282 @@
+use ::chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Utc};
+
+use crate::error;
+
+use super::{
+    decode::Decode,
+    logical_type::{KuzuType, LogicalType},
+    value::{Date, Interval, KuzuValue, Timestamp},
+};
+
+/// Number of days between 0001-01-01 (CE) and 1970-01-01.
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+impl From<NaiveDate> for Date {
+    fn from(value: NaiveDate) -> Self {
+        Self {
+            days: value.num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE,
+        }
+    }
+}
+
+impl From<NaiveDate> for KuzuValue {
+    fn from(value: NaiveDate) -> Self {
+        KuzuValue::Date(value.into())
+    }
+}
+
+impl Decode for NaiveDate {
+    fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
+        match value {
+            KuzuValue::Date(inner) => inner
+                .days
+                .checked_add(UNIX_EPOCH_DAYS_FROM_CE)
+                .and_then(NaiveDate::from_num_days_from_ce_opt)
+                .ok_or(error::Error::DecodeError(
+                    "KuzuValue::Date",
+                    std::any::type_name::<Self>(),
+                )),
+            KuzuValue::Null(_) => Err(error::Error::UnexpectedNull(std::any::type_name::<Self>())),
+            ty => Err(error::Error::DecodeError(
+                ty.name(),
+                std::any::type_name::<Self>(),
+            )),
+        }
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self {
+            micros: value.timestamp_micros(),
+        }
+    }
+}
+
+impl From<DateTime<Utc>> for KuzuValue {
+    fn from(value: DateTime<Utc>) -> Self {
+        KuzuValue::Timestamp(value.into())
+    }
+}
+
+impl Decode for DateTime<Utc> {
+    fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
+        match value {
+            KuzuValue::Timestamp(inner) => DateTime::from_timestamp_micros(inner.micros).ok_or(
+                error::Error::DecodeError("KuzuValue::Timestamp", std::any::type_name::<Self>()),
+            ),
+            KuzuValue::Null(_) => Err(error::Error::UnexpectedNull(std::any::type_name::<Self>())),
+            ty => Err(error::Error::DecodeError(
+                ty.name(),
+                std::any::type_name::<Self>(),
+            )),
+        }
+    }
+}
+
+impl From<NaiveDateTime> for KuzuValue {
+    fn from(value: NaiveDateTime) -> Self {
+        value.and_utc().into()
+    }
+}
+
+impl Decode for NaiveDateTime {
+    fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
+        DateTime::<Utc>::decode_kuzuval(value).map(|inner| inner.naive_utc())
+    }
+}
+
+impl TryFrom<Duration> for Interval {
+    type Error = error::Error;
+
+    fn try_from(value: Duration) -> error::Result<Self> {
+        let micros = value.num_seconds() as i128 * 1_000_000 + value.subsec_nanos() as i128 / 1_000;
+        Interval::try_from_micros(micros, std::any::type_name::<Duration>())
+    }
+}
+
+impl TryFrom<Duration> for KuzuValue {
+    type Error = error::Error;
+
+    fn try_from(value: Duration) -> error::Result<Self> {
+        value.try_into().map(KuzuValue::Interval)
+    }
+}
+
+impl Decode for Duration {
+    fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
+        match value {
+            KuzuValue::Interval(inner) => i64::try_from(inner.total_micros())
+                .map(Duration::microseconds)
+                .map_err(|_| {
+                    error::Error::DecodeError("KuzuValue::Interval", std::any::type_name::<Self>())
+                }),
+            KuzuValue::Null(_) => Err(error::Error::UnexpectedNull(std::any::type_name::<Self>())),
+            ty => Err(error::Error::DecodeError(
+                ty.name(),
+                std::any::type_name::<Self>(),
+            )),
+        }
+    }
+}
+
+impl KuzuType for NaiveDate {
+    fn logical_type() -> LogicalType {
+        LogicalType::Date
+    }
+}
+
+impl KuzuType for DateTime<Utc> {
+    fn logical_type() -> LogicalType {
+        LogicalType::Timestamp
+    }
+}
+
+impl KuzuType for NaiveDateTime {
+    fn logical_type() -> LogicalType {
+        LogicalType::Timestamp
+    }
+}
+
+impl KuzuType for Duration {
+    fn logical_type() -> LogicalType {
+        LogicalType::Interval
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+
+    use crate::error::Error;
+    use crate::types::{
+        decode::Decode,
+        logical_type::KuzuType,
+        row::Row,
+        value::{Date, Interval, KuzuValue, Timestamp},
+    };
+    use crate::{connection::Connection, test_utils::test_database};
+
+    #[test]
+    fn test_date() {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        assert_eq!(KuzuValue::from(epoch), KuzuValue::Date(Date { days: 0 }));
+
+        let date = NaiveDate::from_ymd_opt(2022, 1, 8).unwrap();
+        let encoded = KuzuValue::from(date);
+        assert_eq!(encoded, KuzuValue::Date(Date { days: 19_000 }));
+        assert_eq!(NaiveDate::decode_kuzuval(encoded).unwrap(), date);
+
+        assert!(NaiveDate::decode_kuzuval(KuzuValue::Int32(0)).is_err());
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let datetime = DateTime::<Utc>::from_timestamp_micros(1_641_600_000_123_456).unwrap();
+        let encoded = KuzuValue::from(datetime);
+        assert_eq!(
+            encoded,
+            KuzuValue::Timestamp(Timestamp {
+                micros: 1_641_600_000_123_456
+            })
+        );
+        assert_eq!(
+            DateTime::<Utc>::decode_kuzuval(encoded.clone()).unwrap(),
+            datetime
+        );
+        assert_eq!(
+            NaiveDateTime::decode_kuzuval(encoded).unwrap(),
+            datetime.naive_utc()
+        );
+    }
+
+    #[test]
+    fn test_interval() {
+        let duration = Duration::days(3) + Duration::microseconds(42);
+        let encoded = KuzuValue::try_from(duration).unwrap();
+        assert_eq!(
+            encoded,
+            KuzuValue::Interval(Interval {
+                months: 0,
+                days: 3,
+                micros: 42
+            })
+        );
+        assert_eq!(Duration::decode_kuzuval(encoded).unwrap(), duration);
+
+        let negative = -(Duration::days(1) + Duration::microseconds(5));
+        assert_eq!(
+            Interval::try_from(negative).unwrap(),
+            Interval {
+                months: 0,
+                days: -1,
+                micros: -5
+            }
+        );
+        assert!(matches!(
+            Interval::try_from(Duration::days(i32::MAX as i64 + 1)),
+            Err(Error::EncodeOutOfRange(_, "KuzuValue::Interval"))
+        ));
+
+        let with_months = KuzuValue::Interval(Interval {
+            months: 1,
+            days: 1,
+            micros: 0,
+        });
+        assert_eq!(
+            Duration::decode_kuzuval(with_months).unwrap(),
+            Duration::days(31)
+        );
+    }
+
+    #[test]
+    fn test_null_round_trip() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+        let mut stmt = conn
+            .prepare("RETURN $date AS date, $timestamp AS timestamp, $interval AS interval;")
+            .unwrap();
+        stmt.bind("date", None::<NaiveDate>)
+            .unwrap()
+            .bind("timestamp", None::<DateTime<Utc>>)
+            .unwrap()
+            .bind("interval", KuzuValue::Null(Duration::logical_type()))
+            .unwrap();
+
+        let row = stmt
+            .execute()
+            .unwrap()
+            .iter::<Row>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            row.get_val_by_column::<NaiveDate, _>("date"),
+            Err(Error::UnexpectedNull(_))
+        ));
+        assert!(matches!(
+            row.get_val_by_column::<DateTime<Utc>, _>("timestamp"),
+            Err(Error::UnexpectedNull(_))
+        ));
+        assert!(matches!(
+            row.get_val_by_column::<Duration, _>("interval"),
+            Err(Error::UnexpectedNull(_))
+        ));
+        assert_eq!(
+            row.get_val_by_column::<Option<NaiveDate>, _>("date")
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            row.get_val_by_column::<Option<DateTime<Utc>>, _>("timestamp")
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            row.get_val_by_column::<Option<Duration>, _>("interval")
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use crate::error;
 
-use super::value::{FixedList, KuzuValue, Node, Relation, Struct, VarList};
+use super::value::{
+    Date, FixedList, Interval, KuzuValue, Node, Relation, Struct, Timestamp, VarList,
+};
 
 pub trait Decode: Sized {
     fn decode_kuzuval(value: KuzuValue) -> error::Result<Self>;
@@ -16,6 +18,9 @@ macro_rules! impl_decode {
             fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
                 match value {
                     KuzuValue::$inner(inner) => Ok(inner),
+                    KuzuValue::Null(_) => {
+                        Err(error::Error::UnexpectedNull(std::any::type_name::<Self>()))
+                    }
                     ty => Err(error::Error::DecodeError(
                         ty.name(),
                         std::any::type_name::<Self>(),
@@ -38,6 +43,9 @@ impl_decode!(Relation, Rel);
 impl_decode!(VarList, VarList);
 impl_decode!(FixedList, FixedList);
 impl_decode!(Struct, Struct);
+impl_decode!(Date, Date);
+impl_decode!(Timestamp, Timestamp);
+impl_decode!(Interval, Interval);
 
 impl<T> Decode for Vec<T>
 where
@@ -47,6 +55,7 @@ where
         match value {
             KuzuValue::VarList(inner) => inner.try_into(),
             KuzuValue::FixedList(inner) => inner.try_into(),
+            KuzuValue::Null(_) => Err(error::Error::UnexpectedNull(std::any::type_name::<Self>())),
             ty => Err(error::Error::DecodeError(
                 ty.name(),
                 std::any::type_name::<Self>(),
@@ -55,6 +64,18 @@ where
     }
 }
 
+impl<T> Decode for Option<T>
+where
+    T: Decode,
+{
+    fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
+        match value {
+            KuzuValue::Null(_) => Ok(None),
+            inner => T::decode_kuzuval(inner).map(Some),
+        }
+    }
+}
+
 impl Decode for KuzuValue {
     fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
         Ok(value)
@@ -85,8 +106,9 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{KuzuValue, Node, Relation};
+    use super::{Date, Decode, Interval, KuzuValue, Node, Relation, Timestamp};
     use crate::error;
+    use crate::types::logical_type::LogicalType;
     use crate::types::value::tests::{
         new_fixedlist, new_internal_id, new_node, new_rel, new_varlist,
     };
@@ -179,5 +201,51 @@ mod tests {
             KuzuValue::VarList(varlist.clone()),
             varlist.clone(),
         );
+
+        let date = Date { days: 19_000 };
+        test_type::<Date, bool, i16, i32, i64, f32, f64, String, Timestamp, Interval, VarList>(
+            KuzuValue::Date(date),
+            date,
+        );
+
+        let timestamp = Timestamp {
+            micros: 1_641_600_000_000_000,
+        };
+        test_type::<Timestamp, bool, i16, i32, i64, f32, f64, String, Date, Interval, VarList>(
+            KuzuValue::Timestamp(timestamp),
+            timestamp,
+        );
+
+        let interval = Interval {
+            months: 1,
+            days: 2,
+            micros: 3,
+        };
+        test_type::<Interval, bool, i16, i32, i64, f32, f64, String, Date, Timestamp, VarList>(
+            KuzuValue::Interval(interval),
+            interval,
+        );
+    }
+
+    #[test]
+    fn test_decode_null() {
+        let null = KuzuValue::Null(LogicalType::Int64);
+
+        assert_eq!(Option::<i64>::decode_kuzuval(null.clone()).unwrap(), None);
+        assert_eq!(
+            Option::<i64>::decode_kuzuval(KuzuValue::Int64(64)).unwrap(),
+            Some(64)
+        );
+        assert!(Option::<i64>::decode_kuzuval(KuzuValue::Bool(true)).is_err());
+
+        assert!(matches!(
+            i64::decode_kuzuval(null.clone()),
+            Err(error::Error::UnexpectedNull(_))
+        ));
+        assert!(matches!(
+            Vec::<i64>::decode_kuzuval(null.clone()),
+            Err(error::Error::UnexpectedNull(_))
+        ));
+        assert_eq!(KuzuValue::decode_kuzuval(null.clone()).unwrap(), null);
     }
 }
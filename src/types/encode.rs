@@ -1,4 +1,15 @@
-use super::value::KuzuValue;
+use super::logical_type::KuzuType;
+use super::value::{Date, InternalId, Interval, KuzuValue, Node, Relation, Struct, Timestamp};
+
+/// A trait for Rust types that can be encoded into a Kuzu struct.
+/// Usually derived with `#[derive(ToKuzuStruct)]`.
+///
+/// The C API can't create struct values, so a `Struct` can't be bound to a parameter of a prepared
+/// statement, and there is no `From<T> for KuzuValue`. It can be compared against decoded values instead.
+pub trait ToKuzuStruct {
+    /// Encodes the value into a struct with a field per Rust field.
+    fn to_kuzu_struct(&self) -> Struct;
+}
 
 impl From<bool> for KuzuValue {
     fn from(value: bool) -> Self {
@@ -41,3 +52,85 @@ impl From<&str> for KuzuValue {
         KuzuValue::String(value.to_owned())
     }
 }
+
+impl From<String> for KuzuValue {
+    fn from(value: String) -> Self {
+        KuzuValue::String(value)
+    }
+}
+
+impl From<Date> for KuzuValue {
+    fn from(value: Date) -> Self {
+        KuzuValue::Date(value)
+    }
+}
+
+impl From<Timestamp> for KuzuValue {
+    fn from(value: Timestamp) -> Self {
+        KuzuValue::Timestamp(value)
+    }
+}
+
+impl From<Interval> for KuzuValue {
+    fn from(value: Interval) -> Self {
+        KuzuValue::Interval(value)
+    }
+}
+
+impl From<InternalId> for KuzuValue {
+    fn from(value: InternalId) -> Self {
+        KuzuValue::InternalId(value)
+    }
+}
+
+impl From<Node> for KuzuValue {
+    fn from(value: Node) -> Self {
+        KuzuValue::Node(value)
+    }
+}
+
+impl From<Relation> for KuzuValue {
+    fn from(value: Relation) -> Self {
+        KuzuValue::Rel(value)
+    }
+}
+
+impl<T: Into<KuzuValue> + KuzuType> From<Option<T>> for KuzuValue {
+    /// Encodes `None` as a NULL of the logical type of `T`.
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => value.into(),
+            None => KuzuValue::Null(T::logical_type()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{
+        logical_type::LogicalType,
+        value::{KuzuValue, Struct},
+    };
+
+    #[test]
+    fn test_encode_option() {
+        assert_eq!(KuzuValue::from(Some(1i64)), KuzuValue::Int64(1));
+        assert_eq!(
+            KuzuValue::from(None::<String>),
+            KuzuValue::Null(LogicalType::String)
+        );
+        assert_eq!(
+            KuzuValue::from(None::<&str>),
+            KuzuValue::Null(LogicalType::String)
+        );
+    }
+
+    #[test]
+    fn test_encode_struct() {
+        let value: Struct = [("name".to_owned(), KuzuValue::from("Alice"))]
+            .into_iter()
+            .collect();
+        assert_eq!(value.get("name"), Some(&KuzuValue::from("Alice")));
+        assert_eq!(value.get("NAME"), Some(&KuzuValue::from("Alice")));
+    }
+}
@@ -1,10 +1,13 @@
+use crate::helper::convert_inner_to_owned_string;
 use crate::ptrc::PtrContainer;
 
 use crate::{error, ffi};
 
+use super::value::{Date, InternalId, Interval, Timestamp};
+
 /// Represents the logical type ids used in Kuzu.
 #[repr(u32)]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LogicalTypeID {
     /// Represents any logical type.
     Any = ffi::kuzu_data_type_id_KUZU_ANY,
@@ -70,48 +73,156 @@ impl TryFrom<u32> for LogicalTypeID {
 }
 
 /// Represents a logical type used in Kuzu.
-#[derive(Debug, PartialEq)]
-pub(crate) struct LogicaType {
-    /// The LogicalTypeID of the LogicaType.
-    pub(crate) tid: LogicalTypeID,
-    /// The fixed number of elements in a list.
-    pub(crate) fixed_num_elements_in_list: u64,
+///
+/// Nested types keep their child types, so a `VarList` of `Int64` and a `VarList` of `String` are different types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogicalType {
+    /// Represents any logical type.
+    Any,
+    /// Represents a node logical type.
+    Node,
+    /// Represents a relationship logical type.
+    Rel,
+    /// Represents a boolean logical type.
+    Bool,
+    /// Represents a 64-bit integer logical type.
+    Int64,
+    /// Represents a 32-bit integer logical type.
+    Int32,
+    /// Represents a 16-bit integer logical type.
+    Int16,
+    /// Represents a double-precision floating-point logical type.
+    Double,
+    /// Represents a single-precision floating-point logical type.
+    Float,
+    /// Represents a date logical type.
+    Date,
+    /// Represents a timestamp logical type.
+    Timestamp,
+    /// Represents an interval logical type.
+    Interval,
+    /// Represents an internal ID logical type.
+    InternalId,
+    /// Represents a string logical type.
+    String,
+    /// Represents a fixed list logical type with its child type and number of elements.
+    FixedList(Box<LogicalType>, u64),
+    /// Represents a variable-length list logical type with its child type.
+    VarList(Box<LogicalType>),
+    /// Represents a struct logical type with its field names and types.
+    ///
+    /// The C API doesn't expose the types of the fields of a struct type, so a struct type read from
+    /// Kuzu, e.g. from `QueryResult::columns`, has `Any` as its field types. The field types of a
+    /// struct value are those of its field values.
+    Struct(Vec<(String, LogicalType)>),
 }
 
-impl LogicaType {
-    /// Creates a new `LogicaType` with the provided logical type ID, inner pointer,
-    /// and fixed number of elements in the list.
-    ///
-    /// Returns a the newly created `LogicaType` if successful, or
-    /// an `Error::FFIGotNull` if the inner pointer is null.
-    fn new_with_id(
-        tid: LogicalTypeID,
-        inner: *mut ffi::kuzu_logical_type,
-        fixed_num_elements_in_list: u64,
-    ) -> error::Result<Self> {
-        match inner.is_null() {
-            true => Err(error::Error::FFIGotNull(std::any::type_name::<Self>())),
-            false => Ok(Self {
-                tid,
-                fixed_num_elements_in_list,
-            }),
+impl LogicalType {
+    /// Returns the `LogicalTypeID` of the logical type.
+    pub fn id(&self) -> LogicalTypeID {
+        match self {
+            Self::Any => LogicalTypeID::Any,
+            Self::Node => LogicalTypeID::Node,
+            Self::Rel => LogicalTypeID::Rel,
+            Self::Bool => LogicalTypeID::Bool,
+            Self::Int64 => LogicalTypeID::Int64,
+            Self::Int32 => LogicalTypeID::Int32,
+            Self::Int16 => LogicalTypeID::Int16,
+            Self::Double => LogicalTypeID::Double,
+            Self::Float => LogicalTypeID::Float,
+            Self::Date => LogicalTypeID::Date,
+            Self::Timestamp => LogicalTypeID::Timestamp,
+            Self::Interval => LogicalTypeID::Interval,
+            Self::InternalId => LogicalTypeID::InternalId,
+            Self::String => LogicalTypeID::String,
+            Self::FixedList(..) => LogicalTypeID::FixedList,
+            Self::VarList(_) => LogicalTypeID::VarList,
+            Self::Struct(_) => LogicalTypeID::Struct,
         }
     }
 }
 
-impl TryFrom<&PtrContainer<ffi::kuzu_value>> for LogicaType {
-    type Error = error::Error;
-    fn try_from(value: &PtrContainer<ffi::kuzu_value>) -> Result<Self, Self::Error> {
-        let logical_type =
-            PtrContainer::try_new(unsafe { ffi::kuzu_value_get_data_type(value.0) })?;
-        logical_type.try_into()
+/// The deepest nesting of lists whose child types are recovered from a `kuzu_logical_type`.
+const MAX_LIST_DEPTH: usize = 8;
+
+/// The types without child types, tried as the child types of lists.
+const SCALAR_TYPES: [LogicalType; 13] = [
+    LogicalType::Bool,
+    LogicalType::Int64,
+    LogicalType::Int32,
+    LogicalType::Int16,
+    LogicalType::Double,
+    LogicalType::Float,
+    LogicalType::Date,
+    LogicalType::Timestamp,
+    LogicalType::Interval,
+    LogicalType::InternalId,
+    LogicalType::String,
+    LogicalType::Node,
+    LogicalType::Rel,
+];
+
+impl LogicalType {
+    /// Returns whether the logical type equals the data type, compared by Kuzu.
+    fn equals(&self, data_type: &PtrContainer<ffi::kuzu_logical_type>) -> error::Result<bool> {
+        let other = PtrContainer::try_from(self)?;
+        Ok(unsafe { ffi::kuzu_data_type_equals(data_type.0, other.0) })
+    }
+
+    /// Finds the child type for which `wrap` builds a type equal to the data type.
+    ///
+    /// The C API has no accessor for the child type of a list, so it is probed with `kuzu_data_type_equals`.
+    /// Nested lists are probed down to `depth` levels; fixed lists and structs can't be probed,
+    /// as their lengths and fields are unknown.
+    fn find_child(
+        data_type: &PtrContainer<ffi::kuzu_logical_type>,
+        wrap: &dyn Fn(Self) -> Self,
+        depth: usize,
+    ) -> error::Result<Option<Self>> {
+        for child in SCALAR_TYPES {
+            if wrap(child.clone()).equals(data_type)? {
+                return Ok(Some(child));
+            }
+        }
+        if depth == 0 {
+            return Ok(None);
+        }
+
+        let wrap_list = |child| wrap(Self::VarList(Box::new(child)));
+        let child = Self::find_child(data_type, &wrap_list, depth - 1)?;
+        Ok(child.map(|child| Self::VarList(Box::new(child))))
+    }
+
+    /// Reads the field names of a struct data type, with `Any` as their types.
+    ///
+    /// The C API only exposes the fields of a struct through a value, and the type of a field
+    /// through the value of the field, which a NULL value of the struct type doesn't have.
+    fn struct_fields(
+        data_type: &PtrContainer<ffi::kuzu_logical_type>,
+    ) -> error::Result<Vec<(String, Self)>> {
+        let null = PtrContainer::try_new(unsafe {
+            ffi::kuzu_value_create_null_with_data_type(data_type.0)
+        })?;
+        let num_fields = unsafe { ffi::kuzu_value_get_struct_num_fields(null.0) };
+        (0..num_fields)
+            .map(|idx| {
+                let name = convert_inner_to_owned_string(unsafe {
+                    ffi::kuzu_value_get_struct_field_name(null.0, idx)
+                })?;
+                Ok((name, Self::Any))
+            })
+            .collect()
     }
 }
 
-impl TryFrom<PtrContainer<ffi::kuzu_logical_type>> for LogicaType {
+/// Reads a logical type from a `kuzu_logical_type`.
+///
+/// The child types of lists are recovered with `kuzu_data_type_equals`, falling back to `Any` where
+/// they can't be, e.g. for lists of structs. Struct types keep the names of their fields, but not
+/// their types, which are `Any`.
+impl TryFrom<PtrContainer<ffi::kuzu_logical_type>> for LogicalType {
     type Error = error::Error;
     fn try_from(value: PtrContainer<ffi::kuzu_logical_type>) -> Result<Self, Self::Error> {
-        let value = value;
         let tid = {
             let _tid = unsafe { ffi::kuzu_data_type_get_id(value.0) };
             LogicalTypeID::try_from(_tid)?
@@ -120,6 +231,342 @@ impl TryFrom<PtrContainer<ffi::kuzu_logical_type>> for LogicaType {
         let fixed_num_elements_in_list =
             unsafe { ffi::kuzu_data_type_get_fixed_num_elements_in_list(value.0) };
 
-        Self::new_with_id(tid, value.0, fixed_num_elements_in_list)
+        let res = match tid {
+            LogicalTypeID::Any => Self::Any,
+            LogicalTypeID::Node => Self::Node,
+            LogicalTypeID::Rel => Self::Rel,
+            LogicalTypeID::Bool => Self::Bool,
+            LogicalTypeID::Int64 => Self::Int64,
+            LogicalTypeID::Int32 => Self::Int32,
+            LogicalTypeID::Int16 => Self::Int16,
+            LogicalTypeID::Double => Self::Double,
+            LogicalTypeID::Float => Self::Float,
+            LogicalTypeID::Date => Self::Date,
+            LogicalTypeID::Timestamp => Self::Timestamp,
+            LogicalTypeID::Interval => Self::Interval,
+            LogicalTypeID::InternalId => Self::InternalId,
+            LogicalTypeID::String => Self::String,
+            LogicalTypeID::VarList => {
+                let wrap = |child| Self::VarList(Box::new(child));
+                let child = Self::find_child(&value, &wrap, MAX_LIST_DEPTH)?;
+                Self::VarList(Box::new(child.unwrap_or(Self::Any)))
+            }
+            LogicalTypeID::FixedList => {
+                let wrap = |child| Self::FixedList(Box::new(child), fixed_num_elements_in_list);
+                let child = Self::find_child(&value, &wrap, MAX_LIST_DEPTH)?;
+                Self::FixedList(
+                    Box::new(child.unwrap_or(Self::Any)),
+                    fixed_num_elements_in_list,
+                )
+            }
+            LogicalTypeID::Struct => Self::Struct(Self::struct_fields(&value)?),
+        };
+
+        Ok(res)
+    }
+}
+
+impl TryFrom<&LogicalType> for PtrContainer<ffi::kuzu_logical_type> {
+    type Error = error::Error;
+    fn try_from(value: &LogicalType) -> Result<Self, Self::Error> {
+        let (child, fixed_num_elements_in_list) = match value {
+            LogicalType::FixedList(child, len) => (Some(Self::try_from(child.as_ref())?), *len),
+            LogicalType::VarList(child) => (Some(Self::try_from(child.as_ref())?), 0),
+            // The C API cannot attach field names and types to a struct type, so struct types
+            // only convert from a `kuzu_logical_type`.
+            LogicalType::Struct(_) => {
+                return Err(error::Error::UnsupportedEncode("LogicalType::Struct"))
+            }
+            _ => (None, 0),
+        };
+
+        // `kuzu_data_type_create` copies the child type, so it is dropped afterwards.
+        let child_ptr = child.as_ref().map_or(std::ptr::null_mut(), |child| child.0);
+        PtrContainer::try_new(unsafe {
+            ffi::kuzu_data_type_create(value.id() as u32, child_ptr, fixed_num_elements_in_list)
+        })
+    }
+}
+
+impl std::str::FromStr for LogicalType {
+    type Err = error::Error;
+
+    /// Parses a type name as printed by Kuzu, e.g. `INT64`, `STRING[]`, `DOUBLE[3]` or `STRUCT(a:INT64, b:STRING)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let parse_error = || error::Error::LogicalTypeParseError(s.to_owned());
+
+        if let Some(child) = s.strip_suffix("[]") {
+            return Ok(Self::VarList(Box::new(child.parse()?)));
+        }
+
+        if let Some((child, len)) = s.strip_suffix(']').and_then(|s| s.rsplit_once('[')) {
+            let len = len.trim().parse().map_err(|_| parse_error())?;
+            return Ok(Self::FixedList(Box::new(child.parse()?), len));
+        }
+
+        if let Some(fields) = s
+            .strip_suffix(')')
+            .and_then(|s| s.split_once('('))
+            .filter(|(name, _)| name.trim().eq_ignore_ascii_case("STRUCT"))
+            .map(|(_, fields)| fields)
+        {
+            let fields = split_top_level(fields)
+                .into_iter()
+                .map(str::trim)
+                .filter(|field| !field.is_empty())
+                .map(|field| {
+                    let (name, ty) = field
+                        .split_once(|c: char| c == ':' || c.is_whitespace())
+                        .ok_or_else(parse_error)?;
+                    Ok((name.trim().to_owned(), ty.parse()?))
+                })
+                .collect::<error::Result<_>>()?;
+            return Ok(Self::Struct(fields));
+        }
+
+        let res = match s.to_ascii_uppercase().as_str() {
+            "ANY" => Self::Any,
+            "NODE" => Self::Node,
+            "REL" => Self::Rel,
+            "BOOL" | "BOOLEAN" => Self::Bool,
+            "INT64" => Self::Int64,
+            "INT32" => Self::Int32,
+            "INT16" => Self::Int16,
+            "DOUBLE" => Self::Double,
+            "FLOAT" => Self::Float,
+            "DATE" => Self::Date,
+            "TIMESTAMP" => Self::Timestamp,
+            "INTERVAL" => Self::Interval,
+            "INTERNAL_ID" => Self::InternalId,
+            "STRING" => Self::String,
+            _ => return Err(parse_error()),
+        };
+
+        Ok(res)
+    }
+}
+
+impl std::fmt::Display for LogicalType {
+    /// Formats the type name as Kuzu prints and parses it, e.g. `INT64`, `STRING[]` or `STRUCT(a INT64)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Any => f.write_str("ANY"),
+            Self::Node => f.write_str("NODE"),
+            Self::Rel => f.write_str("REL"),
+            Self::Bool => f.write_str("BOOL"),
+            Self::Int64 => f.write_str("INT64"),
+            Self::Int32 => f.write_str("INT32"),
+            Self::Int16 => f.write_str("INT16"),
+            Self::Double => f.write_str("DOUBLE"),
+            Self::Float => f.write_str("FLOAT"),
+            Self::Date => f.write_str("DATE"),
+            Self::Timestamp => f.write_str("TIMESTAMP"),
+            Self::Interval => f.write_str("INTERVAL"),
+            Self::InternalId => f.write_str("INTERNAL_ID"),
+            Self::String => f.write_str("STRING"),
+            Self::FixedList(child, len) => write!(f, "{child}[{len}]"),
+            Self::VarList(child) => write!(f, "{child}[]"),
+            Self::Struct(fields) => {
+                f.write_str("STRUCT(")?;
+                for (idx, (name, ty)) in fields.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{name} {ty}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// A trait for Rust types stored in Kuzu as a single logical type.
+///
+/// Used to map the fields of the table derives to the types of their properties.
+pub trait KuzuType {
+    /// Returns the logical type the values of the type are stored as.
+    fn logical_type() -> LogicalType;
+}
+
+macro_rules! impl_kuzu_type {
+    ($($ty:ty => $logical_type:ident),* $(,)?) => {
+        $(
+            impl KuzuType for $ty {
+                fn logical_type() -> LogicalType {
+                    LogicalType::$logical_type
+                }
+            }
+        )*
+    };
+}
+
+impl_kuzu_type! {
+    bool => Bool,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    f32 => Float,
+    f64 => Double,
+    String => String,
+    Date => Date,
+    Timestamp => Timestamp,
+    Interval => Interval,
+    InternalId => InternalId,
+}
+
+impl KuzuType for &str {
+    fn logical_type() -> LogicalType {
+        LogicalType::String
+    }
+}
+
+impl<T: KuzuType> KuzuType for Option<T> {
+    fn logical_type() -> LogicalType {
+        T::logical_type()
+    }
+}
+
+impl<T: KuzuType> KuzuType for Vec<T> {
+    fn logical_type() -> LogicalType {
+        LogicalType::VarList(Box::new(T::logical_type()))
+    }
+}
+
+impl<T: KuzuType, const N: usize> KuzuType for [T; N] {
+    fn logical_type() -> LogicalType {
+        LogicalType::FixedList(Box::new(T::logical_type()), N as u64)
+    }
+}
+
+/// Splits `s` at the commas that are not nested inside parentheses or brackets.
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (idx, c) in s.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&s[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KuzuType, LogicalType};
+    use crate::{connection::Connection, ffi, ptrc::PtrContainer, test_utils::test_database};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("INT64".parse::<LogicalType>().unwrap(), LogicalType::Int64);
+        assert_eq!(
+            "string".parse::<LogicalType>().unwrap(),
+            LogicalType::String
+        );
+        assert_eq!(
+            "INT64[]".parse::<LogicalType>().unwrap(),
+            LogicalType::VarList(Box::new(LogicalType::Int64))
+        );
+        assert_eq!(
+            "DOUBLE[3][]".parse::<LogicalType>().unwrap(),
+            LogicalType::VarList(Box::new(LogicalType::FixedList(
+                Box::new(LogicalType::Double),
+                3
+            )))
+        );
+        assert_eq!(
+            "STRUCT(a:INT64, b:STRUCT(c:STRING[], d:DATE), )"
+                .parse::<LogicalType>()
+                .unwrap(),
+            LogicalType::Struct(vec![
+                ("a".to_owned(), LogicalType::Int64),
+                (
+                    "b".to_owned(),
+                    LogicalType::Struct(vec![
+                        (
+                            "c".to_owned(),
+                            LogicalType::VarList(Box::new(LogicalType::String))
+                        ),
+                        ("d".to_owned(), LogicalType::Date),
+                    ])
+                ),
+            ])
+        );
+        assert!("INT128".parse::<LogicalType>().is_err());
+        assert!("INT64[x]".parse::<LogicalType>().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for name in [
+            "INT64",
+            "STRING[]",
+            "DOUBLE[3][]",
+            "STRUCT(a INT64, b STRUCT(c STRING[], d DATE))",
+        ] {
+            let ty = name.parse::<LogicalType>().unwrap();
+            assert_eq!(ty.to_string(), name);
+        }
+        assert_eq!(
+            <Vec<Option<[f32; 2]>>>::logical_type().to_string(),
+            "FLOAT[2][]"
+        );
+    }
+
+    #[test]
+    fn test_data_type_round_trip() {
+        let types = [
+            LogicalType::Int64,
+            LogicalType::VarList(Box::new(LogicalType::String)),
+            LogicalType::VarList(Box::new(LogicalType::VarList(Box::new(LogicalType::Date)))),
+            LogicalType::FixedList(Box::new(LogicalType::Double), 3),
+        ];
+        for ty in types {
+            let data_type: PtrContainer<ffi::kuzu_logical_type> = (&ty).try_into().unwrap();
+            assert_eq!(LogicalType::try_from(data_type).unwrap(), ty);
+        }
+
+        let unknown_child = PtrContainer::try_from(&LogicalType::VarList(Box::new(
+            LogicalType::FixedList(Box::new(LogicalType::Int64), 2),
+        )))
+        .unwrap();
+        assert_eq!(
+            LogicalType::try_from(unknown_child).unwrap(),
+            LogicalType::VarList(Box::new(LogicalType::Any))
+        );
+    }
+
+    #[test]
+    fn test_struct_data_type() {
+        let (_dir, db) = test_database();
+        let conn = Connection::new(&db).unwrap();
+
+        let result = conn.query("RETURN {name: 'Alice', age: 35} AS s;").unwrap();
+        let (_, ty) = result.columns().unwrap().remove(0);
+        let LogicalType::Struct(fields) = ty else {
+            panic!("expected a struct type, got {ty:?}");
+        };
+        // Kuzu may uppercase the field names, which are case-insensitive.
+        let fields: Vec<_> = fields
+            .into_iter()
+            .map(|(name, ty)| (name.to_uppercase(), ty))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("NAME".to_owned(), LogicalType::Any),
+                ("AGE".to_owned(), LogicalType::Any),
+            ]
+        );
     }
 }
@@ -2,13 +2,21 @@
 pub mod decode;
 
 /// Module for encoding functionality.
-pub(super) mod encode;
+pub mod encode;
 
 /// Module for logical types.
-mod logical_type;
+pub mod logical_type;
 
 /// Module for working with rows.
 pub mod row;
 
 /// Module for working with values.
 pub mod value;
+
+/// Module for `chrono` integration of temporal values.
+#[cfg(feature = "chrono")]
+mod chrono;
+
+/// Module for `time` integration of temporal values.
+#[cfg(feature = "time")]
+mod time;
@@ -1,20 +1,20 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{collections::HashMap, sync::Arc};
 
 use crate::error;
 
 use super::{decode::Decode, value::KuzuValue};
 
 /// Represents a row in Kuzu.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Row {
     /// The mapping of keys (column names) to their respective indices in the `values` vector.
-    keys: Rc<HashMap<String, usize>>,
+    keys: Arc<HashMap<String, usize>>,
     /// The actual values of the row.
     values: Vec<KuzuValue>,
 }
 
 impl Row {
-    pub(crate) fn new(keys: Rc<HashMap<String, usize>>, values: Vec<KuzuValue>) -> Self {
+    pub(crate) fn new(keys: Arc<HashMap<String, usize>>, values: Vec<KuzuValue>) -> Self {
         Self { keys, values }
     }
 
@@ -63,6 +63,25 @@ impl Row {
         let inner_val = unsafe { self.values.get_unchecked(*key_idx) };
         T::decode_kuzuval(inner_val.clone())
     }
+
+    /// Returns the converted value like `get_val`, but wraps a decoding error into an
+    /// `Error::ColumnDecodeError` naming the column.
+    pub(crate) fn decode_column<T: Decode>(&self, idx: usize) -> error::Result<T> {
+        self.get_val(idx).map_err(|e| match e {
+            error::Error::ColumnNotFound(..) => e,
+            e => error::Error::ColumnDecodeError {
+                column: column_name(&self.keys, idx),
+                source: Box::new(e),
+            },
+        })
+    }
+}
+
+/// Returns the name of the column at the given index, or the index itself if the column is unnamed.
+pub(crate) fn column_name(keys: &HashMap<String, usize>, idx: usize) -> String {
+    keys.iter()
+        .find_map(|(name, key_idx)| (*key_idx == idx).then(|| name.clone()))
+        .unwrap_or_else(|| idx.to_string())
 }
 
 // Macro to generate TryFrom<Row> implementations for tuples of varying lengths
@@ -77,7 +96,7 @@ macro_rules! impl_from_row_for_tuple {
 
             #[inline]
             fn try_from(row: Row) -> Result<Self, Self::Error> {
-                Ok(($(row.get_val($idx)?,)+))
+                Ok(($(row.decode_column($idx)?,)+))
              }
         }
     };
@@ -266,3 +285,51 @@ impl_from_row_for_tuple!(
     (14) -> T15;
     (15) -> T16;
 );
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc};
+
+    use super::Row;
+    use crate::{error, types::value::KuzuValue};
+
+    fn new_row() -> Row {
+        let keys = HashMap::from([("a.name".to_owned(), 0), ("a.age".to_owned(), 1)]);
+        Row::new(
+            Arc::new(keys),
+            vec![KuzuValue::String("Alice".to_owned()), KuzuValue::Int64(35)],
+        )
+    }
+
+    #[test]
+    fn test_get_val() {
+        let row = new_row();
+        assert_eq!(row.get_val::<String>(0).unwrap(), "Alice");
+        assert_eq!(row.get_val_by_column::<i64, _>("a.age").unwrap(), 35);
+        assert!(matches!(
+            row.get_val::<i64>(2),
+            Err(error::Error::ColumnNotFound(..))
+        ));
+    }
+
+    #[test]
+    fn test_decode_error_names_column() {
+        let row = new_row();
+        assert!(matches!(
+            row.get_val::<bool>(1),
+            Err(error::Error::DecodeError(..))
+        ));
+        assert!(matches!(
+            row.get_val_by_column::<i64, _>("a.name"),
+            Err(error::Error::DecodeError(..))
+        ));
+
+        match <(String, bool)>::try_from(row) {
+            Err(error::Error::ColumnDecodeError { column, source }) => {
+                assert_eq!(column, "a.age");
+                assert!(matches!(*source, error::Error::DecodeError(..)));
+            }
+            res => panic!("unexpected result {res:?}"),
+        }
+    }
+}
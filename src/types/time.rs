@@ -0,0 +1,294 @@
+use ::time::{Duration, OffsetDateTime, PrimitiveDateTime};
+
+use crate::error;
+
+use super::{
+    decode::Decode,
+    logical_type::{KuzuType, LogicalType},
+    value::{Date, Interval, KuzuValue, Timestamp},
+};
+
+/// Julian day number of 1970-01-01.
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+impl From<::time::Date> for Date {
+    fn from(value: ::time::Date) -> Self {
+        Self {
+            days: value.to_julian_day() - UNIX_EPOCH_JULIAN_DAY,
+        }
+    }
+}
+
+impl From<::time::Date> for KuzuValue {
+    fn from(value: ::time::Date) -> Self {
+        KuzuValue::Date(value.into())
+    }
+}
+
+impl Decode for ::time::Date {
+    fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
+        match value {
+            KuzuValue::Date(inner) => inner
+                .days
+                .checked_add(UNIX_EPOCH_JULIAN_DAY)
+                .and_then(|julian_day| ::time::Date::from_julian_day(julian_day).ok())
+                .ok_or(error::Error::DecodeError(
+                    "KuzuValue::Date",
+                    std::any::type_name::<Self>(),
+                )),
+            KuzuValue::Null(_) => Err(error::Error::UnexpectedNull(std::any::type_name::<Self>())),
+            ty => Err(error::Error::DecodeError(
+                ty.name(),
+                std::any::type_name::<Self>(),
+            )),
+        }
+    }
+}
+
+impl From<OffsetDateTime> for Timestamp {
+    fn from(value: OffsetDateTime) -> Self {
+        Self {
+            micros: (value.unix_timestamp_nanos() / 1_000) as i64,
+        }
+    }
+}
+
+impl From<OffsetDateTime> for KuzuValue {
+    fn from(value: OffsetDateTime) -> Self {
+        KuzuValue::Timestamp(value.into())
+    }
+}
+
+impl Decode for OffsetDateTime {
+    fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
+        match value {
+            KuzuValue::Timestamp(inner) => OffsetDateTime::from_unix_timestamp_nanos(
+                inner.micros as i128 * 1_000,
+            )
+            .map_err(|_| {
+                error::Error::DecodeError("KuzuValue::Timestamp", std::any::type_name::<Self>())
+            }),
+            KuzuValue::Null(_) => Err(error::Error::UnexpectedNull(std::any::type_name::<Self>())),
+            ty => Err(error::Error::DecodeError(
+                ty.name(),
+                std::any::type_name::<Self>(),
+            )),
+        }
+    }
+}
+
+impl From<PrimitiveDateTime> for KuzuValue {
+    fn from(value: PrimitiveDateTime) -> Self {
+        value.assume_utc().into()
+    }
+}
+
+impl Decode for PrimitiveDateTime {
+    fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
+        OffsetDateTime::decode_kuzuval(value)
+            .map(|inner| PrimitiveDateTime::new(inner.date(), inner.time()))
+    }
+}
+
+impl TryFrom<Duration> for Interval {
+    type Error = error::Error;
+
+    fn try_from(value: Duration) -> error::Result<Self> {
+        Interval::try_from_micros(
+            value.whole_microseconds(),
+            std::any::type_name::<Duration>(),
+        )
+    }
+}
+
+impl TryFrom<Duration> for KuzuValue {
+    type Error = error::Error;
+
+    fn try_from(value: Duration) -> error::Result<Self> {
+        value.try_into().map(KuzuValue::Interval)
+    }
+}
+
+impl Decode for Duration {
+    fn decode_kuzuval(value: KuzuValue) -> error::Result<Self> {
+        match value {
+            KuzuValue::Interval(inner) => {
+                let micros = inner.total_micros();
+                // The seconds of the longest interval, about 2^31 months, fit into an `i64`.
+                Ok(Duration::new(
+                    (micros / 1_000_000) as i64,
+                    (micros % 1_000_000 * 1_000) as i32,
+                ))
+            }
+            KuzuValue::Null(_) => Err(error::Error::UnexpectedNull(std::any::type_name::<Self>())),
+            ty => Err(error::Error::DecodeError(
+                ty.name(),
+                std::any::type_name::<Self>(),
+            )),
+        }
+    }
+}
+
+impl KuzuType for ::time::Date {
+    fn logical_type() -> LogicalType {
+        LogicalType::Date
+    }
+}
+
+impl KuzuType for OffsetDateTime {
+    fn logical_type() -> LogicalType {
+        LogicalType::Timestamp
+    }
+}
+
+impl KuzuType for PrimitiveDateTime {
+    fn logical_type() -> LogicalType {
+        LogicalType::Timestamp
+    }
+}
+
+impl KuzuType for Duration {
+    fn logical_type() -> LogicalType {
+        LogicalType::Interval
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::time::{Duration, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+    use crate::error::Error;
+    use crate::types::{
+        decode::Decode,
+        logical_type::KuzuType,
+        row::Row,
+        value::{Date, Interval, KuzuValue, Timestamp},
+    };
+    use crate::{connection::Connection, test_utils::test_database};
+
+    #[test]
+    fn test_date() {
+        let epoch = ::time::Date::from_calendar_date(1970, Month::January, 1).unwrap();
+        assert_eq!(KuzuValue::from(epoch), KuzuValue::Date(Date { days: 0 }));
+
+        let date = ::time::Date::from_calendar_date(2022, Month::January, 8).unwrap();
+        let encoded = KuzuValue::from(date);
+        assert_eq!(encoded, KuzuValue::Date(Date { days: 19_000 }));
+        assert_eq!(::time::Date::decode_kuzuval(encoded).unwrap(), date);
+
+        assert!(::time::Date::decode_kuzuval(KuzuValue::Int32(0)).is_err());
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let date = ::time::Date::from_calendar_date(2022, Month::January, 8).unwrap();
+        let primitive =
+            PrimitiveDateTime::new(date, Time::from_hms_micro(0, 0, 0, 123_456).unwrap());
+        let datetime = primitive.assume_utc();
+        let encoded = KuzuValue::from(datetime);
+        assert_eq!(
+            encoded,
+            KuzuValue::Timestamp(Timestamp {
+                micros: 1_641_600_000_123_456
+            })
+        );
+        assert_eq!(
+            OffsetDateTime::decode_kuzuval(encoded.clone()).unwrap(),
+            datetime
+        );
+        assert_eq!(
+            PrimitiveDateTime::decode_kuzuval(encoded).unwrap(),
+            primitive
+        );
+    }
+
+    #[test]
+    fn test_interval() {
+        let duration = Duration::days(3) + Duration::microseconds(42);
+        let encoded = KuzuValue::try_from(duration).unwrap();
+        assert_eq!(
+            encoded,
+            KuzuValue::Interval(Interval {
+                months: 0,
+                days: 3,
+                micros: 42
+            })
+        );
+        assert_eq!(Duration::decode_kuzuval(encoded).unwrap(), duration);
+
+        let negative = -(Duration::days(1) + Duration::microseconds(5));
+        assert_eq!(
+            Interval::try_from(negative).unwrap(),
+            Interval {
+                months: 0,
+                days: -1,
+                micros: -5
+            }
+        );
+        assert!(matches!(
+            Interval::try_from(Duration::days(i32::MAX as i64 + 1)),
+            Err(Error::EncodeOutOfRange(_, "KuzuValue::Interval"))
+        ));
+
+        let with_months = KuzuValue::Interval(Interval {
+            months: 1,
+            days: 1,
+            micros: 0,
+        });
+        assert_eq!(
+            Duration::decode_kuzuval(with_months).unwrap(),
+            Duration::days(31)
+        );
+    }
+
+    #[test]
+    fn test_null_round_trip() {
+        let (_dir, db) = test_database();
+        let mut conn = Connection::new(&db).unwrap();
+        let mut stmt = conn
+            .prepare("RETURN $date AS date, $timestamp AS timestamp, $interval AS interval;")
+            .unwrap();
+        stmt.bind("date", None::<::time::Date>)
+            .unwrap()
+            .bind("timestamp", None::<OffsetDateTime>)
+            .unwrap()
+            .bind("interval", KuzuValue::Null(Duration::logical_type()))
+            .unwrap();
+
+        let row = stmt
+            .execute()
+            .unwrap()
+            .iter::<Row>()
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            row.get_val_by_column::<::time::Date, _>("date"),
+            Err(Error::UnexpectedNull(_))
+        ));
+        assert!(matches!(
+            row.get_val_by_column::<OffsetDateTime, _>("timestamp"),
+            Err(Error::UnexpectedNull(_))
+        ));
+        assert!(matches!(
+            row.get_val_by_column::<Duration, _>("interval"),
+            Err(Error::UnexpectedNull(_))
+        ));
+        assert_eq!(
+            row.get_val_by_column::<Option<::time::Date>, _>("date")
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            row.get_val_by_column::<Option<OffsetDateTime>, _>("timestamp")
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            row.get_val_by_column::<Option<Duration>, _>("interval")
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::{error, helper::convert_inner_to_owned_string, into_cstr, ptrc::PtrContainer};
 
-use super::logical_type::{LogicaType, LogicalTypeID};
+use super::logical_type::{LogicalType, LogicalTypeID};
 
 use crate::ffi;
 
@@ -35,9 +35,14 @@ pub enum KuzuValue {
     VarList(VarList),
     /// Custom Struct
     Struct(Struct),
-    // Date,
-    // Timestamp,
-    // Interval,
+    /// Date value.
+    Date(Date),
+    /// Timestamp value.
+    Timestamp(Timestamp),
+    /// Interval value.
+    Interval(Interval),
+    /// NULL value of the given logical type.
+    Null(LogicalType),
 }
 
 impl KuzuValue {
@@ -57,6 +62,10 @@ impl KuzuValue {
             Self::FixedList(_) => "KuzuValue::FixedList",
             Self::Struct(_) => "KuzuValue::Struct",
             Self::VarList(_) => "KuzuValue::VarList",
+            Self::Date(_) => "KuzuValue::Date",
+            Self::Timestamp(_) => "KuzuValue::Timestamp",
+            Self::Interval(_) => "KuzuValue::Interval",
+            Self::Null(_) => "KuzuValue::Null",
         }
     }
 }
@@ -64,10 +73,17 @@ impl TryFrom<PtrContainer<ffi::kuzu_value>> for KuzuValue {
     type Error = error::Error;
 
     fn try_from(value: PtrContainer<ffi::kuzu_value>) -> Result<Self, Self::Error> {
-        let logical_type = LogicaType::try_from(&value)?;
         let inner_ptr = value.0;
+        let data_type: PtrContainer<ffi::kuzu_logical_type> =
+            PtrContainer::try_new(unsafe { ffi::kuzu_value_get_data_type(inner_ptr) })?;
 
-        let res = match logical_type.tid {
+        if unsafe { ffi::kuzu_value_is_null(inner_ptr) } {
+            return Ok(Self::Null(data_type.try_into()?));
+        }
+
+        // Only the id is needed to read a value, the child types of lists and structs are read with their elements.
+        let tid = LogicalTypeID::try_from(unsafe { ffi::kuzu_data_type_get_id(data_type.0) })?;
+        let res = match tid {
             LogicalTypeID::Bool => Self::Bool(unsafe { ffi::kuzu_value_get_bool(inner_ptr) }),
             LogicalTypeID::Int16 => Self::Int16(unsafe { ffi::kuzu_value_get_int16(inner_ptr) }),
             LogicalTypeID::Int32 => Self::Int32(unsafe { ffi::kuzu_value_get_int32(inner_ptr) }),
@@ -93,7 +109,9 @@ impl TryFrom<PtrContainer<ffi::kuzu_value>> for KuzuValue {
                 Self::InternalId(internal_id)
             }
             LogicalTypeID::FixedList => {
-                let elems = (0..(logical_type.fixed_num_elements_in_list))
+                let len =
+                    unsafe { ffi::kuzu_data_type_get_fixed_num_elements_in_list(data_type.0) };
+                let elems = (0..len)
                     .map(|idx| {
                         PtrContainer::try_new(unsafe {
                             ffi::kuzu_value_get_list_element(inner_ptr, idx)
@@ -105,11 +123,19 @@ impl TryFrom<PtrContainer<ffi::kuzu_value>> for KuzuValue {
             }
             LogicalTypeID::VarList => Self::VarList(VarList::try_from(value)?),
             LogicalTypeID::Struct => Self::Struct(value.try_into()?),
-            // LogicalTypeID::Date => todo!(),
-            // LogicalTypeID::Timestamp => todo!(),
-            // LogicalTypeID::Interval => todo!(),
-            // LogicalTypeID::Any => todo!(),
-            ty => todo!("{:?}", ty),
+            LogicalTypeID::Date => {
+                Self::Date(unsafe { ffi::kuzu_value_get_date(inner_ptr) }.into())
+            }
+            LogicalTypeID::Timestamp => {
+                Self::Timestamp(unsafe { ffi::kuzu_value_get_timestamp(inner_ptr) }.into())
+            }
+            LogicalTypeID::Interval => {
+                Self::Interval(unsafe { ffi::kuzu_value_get_interval(inner_ptr) }.into())
+            }
+            // Kuzu only leaves the type of a value undetermined for NULL, which is handled above.
+            LogicalTypeID::Any => {
+                return Err(error::Error::DecodeError("LogicalTypeID::Any", "KuzuValue"))
+            }
         };
 
         Ok(res)
@@ -121,8 +147,14 @@ impl TryFrom<&KuzuValue> for PtrContainer<ffi::kuzu_value> {
     fn try_from(value: &KuzuValue) -> Result<Self, Self::Error> {
         let res = unsafe {
             match value {
-                KuzuValue::Node(_inner) => todo!(),
-                KuzuValue::Rel(_inner) => todo!(),
+                KuzuValue::Node(inner) => {
+                    let node_val = PtrContainer::try_from(inner)?;
+                    ffi::kuzu_value_create_node_val(node_val.0)
+                }
+                KuzuValue::Rel(inner) => {
+                    let rel_val = PtrContainer::try_from(inner)?;
+                    ffi::kuzu_value_create_rel_val(rel_val.0)
+                }
                 KuzuValue::Bool(inner) => ffi::kuzu_value_create_bool(*inner),
                 KuzuValue::Int64(inner) => ffi::kuzu_value_create_int64(*inner),
                 KuzuValue::Int32(inner) => ffi::kuzu_value_create_int32(*inner),
@@ -132,7 +164,18 @@ impl TryFrom<&KuzuValue> for PtrContainer<ffi::kuzu_value> {
                 KuzuValue::String(inner) => {
                     ffi::kuzu_value_create_string(into_cstr!(inner.as_str())?.as_ptr())
                 }
-                _ => todo!(),
+                KuzuValue::Date(inner) => ffi::kuzu_value_create_date(inner.into()),
+                KuzuValue::Timestamp(inner) => ffi::kuzu_value_create_timestamp(inner.into()),
+                KuzuValue::Interval(inner) => ffi::kuzu_value_create_interval(inner.into()),
+                KuzuValue::Null(inner) => {
+                    let logical_type = PtrContainer::try_from(inner)?;
+                    ffi::kuzu_value_create_null_with_data_type(logical_type.0)
+                }
+                KuzuValue::InternalId(inner) => ffi::kuzu_value_create_internal_id(inner.into()),
+                // The C API has no constructor for list and struct values.
+                KuzuValue::FixedList(_) | KuzuValue::VarList(_) | KuzuValue::Struct(_) => {
+                    return Err(error::Error::UnsupportedEncode(value.name()))
+                }
             }
         };
         PtrContainer::try_new(res)
@@ -157,6 +200,118 @@ impl From<ffi::kuzu_internal_id_t> for InternalId {
     }
 }
 
+impl From<&InternalId> for ffi::kuzu_internal_id_t {
+    fn from(value: &InternalId) -> Self {
+        Self {
+            offset: value.offset as u64,
+            table_id: value.table_id as u64,
+        }
+    }
+}
+
+/// Represents a date in Kuzu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    /// The number of days since 1970-01-01.
+    pub days: i32,
+}
+
+impl From<ffi::kuzu_date_t> for Date {
+    fn from(value: ffi::kuzu_date_t) -> Self {
+        Self { days: value.days }
+    }
+}
+
+impl From<&Date> for ffi::kuzu_date_t {
+    fn from(value: &Date) -> Self {
+        Self { days: value.days }
+    }
+}
+
+/// Represents a timestamp in Kuzu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    /// The number of microseconds since 1970-01-01 00:00:00 UTC.
+    pub micros: i64,
+}
+
+impl From<ffi::kuzu_timestamp_t> for Timestamp {
+    fn from(value: ffi::kuzu_timestamp_t) -> Self {
+        Self {
+            micros: value.value,
+        }
+    }
+}
+
+impl From<&Timestamp> for ffi::kuzu_timestamp_t {
+    fn from(value: &Timestamp) -> Self {
+        Self {
+            value: value.micros,
+        }
+    }
+}
+
+/// Represents an interval in Kuzu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    /// The number of months.
+    pub months: i32,
+    /// The number of days.
+    pub days: i32,
+    /// The number of microseconds.
+    pub micros: i64,
+}
+
+/// Kuzu treats a month as 30 days when an interval is flattened into a single duration.
+#[cfg(any(feature = "chrono", feature = "time"))]
+const DAYS_PER_MONTH: i128 = 30;
+
+/// Number of microseconds in a day.
+#[cfg(any(feature = "chrono", feature = "time"))]
+const MICROS_PER_DAY: i128 = 86_400_000_000;
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl Interval {
+    /// Creates an interval of `micros` microseconds, split into whole days and the microseconds left.
+    /// Returns `Error::EncodeOutOfRange` naming `source` if the number of days doesn't fit into an `i32`.
+    pub(crate) fn try_from_micros(micros: i128, source: &'static str) -> error::Result<Self> {
+        let days = i32::try_from(micros / MICROS_PER_DAY)
+            .map_err(|_| error::Error::EncodeOutOfRange(source, "KuzuValue::Interval"))?;
+        Ok(Self {
+            months: 0,
+            days,
+            // The remainder is always shorter than a day, so it fits into an `i64`.
+            micros: (micros % MICROS_PER_DAY) as i64,
+        })
+    }
+
+    /// Returns the length of the interval in microseconds, counting a month as 30 days.
+    pub(crate) fn total_micros(&self) -> i128 {
+        (self.months as i128 * DAYS_PER_MONTH + self.days as i128) * MICROS_PER_DAY
+            + self.micros as i128
+    }
+}
+
+impl From<ffi::kuzu_interval_t> for Interval {
+    fn from(value: ffi::kuzu_interval_t) -> Self {
+        Self {
+            months: value.months,
+            days: value.days,
+            micros: value.micros,
+        }
+    }
+}
+
+impl From<&Interval> for ffi::kuzu_interval_t {
+    fn from(value: &Interval) -> Self {
+        Self {
+            months: value.months,
+            days: value.days,
+            micros: value.micros,
+        }
+    }
+}
+
 /// Represents a node in Kuzu.
 #[derive(Debug, Clone)]
 pub struct Node {
@@ -218,12 +373,47 @@ impl TryFrom<PtrContainer<ffi::kuzu_node_val>> for Node {
     }
 }
 
+impl TryFrom<&Node> for PtrContainer<ffi::kuzu_node_val> {
+    type Error = error::Error;
+    fn try_from(value: &Node) -> Result<Self, Self::Error> {
+        let label = into_cstr!(value.label.as_str())?;
+        let node_val = PtrContainer::try_new(unsafe {
+            ffi::kuzu_node_val_create((&value.id).into(), label.as_ptr())
+        })?;
+
+        for (key, val) in &value.properties {
+            let key = into_cstr!(key.as_str())?;
+            let val = PtrContainer::try_from(val)?;
+            unsafe { ffi::kuzu_node_val_add_property(node_val.0, key.as_ptr(), val.0) };
+        }
+
+        Ok(node_val)
+    }
+}
+
 impl PartialEq for Node {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
     }
 }
 
+impl Node {
+    /// Returns the ID of the node.
+    pub fn id(&self) -> &InternalId {
+        &self.id
+    }
+
+    /// Returns the label of the node.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the properties of the node.
+    pub fn properties(&self) -> &HashMap<String, KuzuValue> {
+        &self.properties
+    }
+}
+
 /// Represents a relation in Kuzu.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Relation {
@@ -237,6 +427,43 @@ pub struct Relation {
     properties: HashMap<String, KuzuValue>,
 }
 
+impl Relation {
+    /// Creates a new relation from `src` to `dst`, e.g. to bind it as a parameter.
+    pub fn new<S: Into<String>>(
+        label: S,
+        src: InternalId,
+        dst: InternalId,
+        properties: HashMap<String, KuzuValue>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            src,
+            dst,
+            properties,
+        }
+    }
+
+    /// Returns the label of the relation.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the source node ID of the relation.
+    pub fn src(&self) -> &InternalId {
+        &self.src
+    }
+
+    /// Returns the destination node ID of the relation.
+    pub fn dst(&self) -> &InternalId {
+        &self.dst
+    }
+
+    /// Returns the properties of the relation.
+    pub fn properties(&self) -> &HashMap<String, KuzuValue> {
+        &self.properties
+    }
+}
+
 impl TryFrom<PtrContainer<ffi::kuzu_rel_val>> for Relation {
     type Error = error::Error;
 
@@ -289,6 +516,24 @@ impl TryFrom<PtrContainer<ffi::kuzu_rel_val>> for Relation {
     }
 }
 
+impl TryFrom<&Relation> for PtrContainer<ffi::kuzu_rel_val> {
+    type Error = error::Error;
+    fn try_from(value: &Relation) -> Result<Self, Self::Error> {
+        let label = into_cstr!(value.label.as_str())?;
+        let rel_val = PtrContainer::try_new(unsafe {
+            ffi::kuzu_rel_val_create((&value.src).into(), (&value.dst).into(), label.as_ptr())
+        })?;
+
+        for (key, val) in &value.properties {
+            let key = into_cstr!(key.as_str())?;
+            let val = PtrContainer::try_from(val)?;
+            unsafe { ffi::kuzu_rel_val_add_property(rel_val.0, key.as_ptr(), val.0) };
+        }
+
+        Ok(rel_val)
+    }
+}
+
 /// Represents a fixed list of values in Kuzu.
 #[derive(Debug, Clone, PartialEq)]
 pub struct FixedList {
@@ -302,11 +547,11 @@ pub struct FixedList {
 impl FixedList {
     #[inline]
     fn try_new(inner: Vec<KuzuValue>) -> error::Result<Self> {
-        if let Some(first_elem) = inner.get(0) {
+        // NULL elements are allowed alongside values of any type.
+        let mut non_null = inner.iter().filter(|v| !matches!(v, KuzuValue::Null(_)));
+        if let Some(first_elem) = non_null.next() {
             let first_elems_type = std::mem::discriminant(first_elem);
-            let is_all_same = inner
-                .iter()
-                .all(|v| std::mem::discriminant(v) == first_elems_type);
+            let is_all_same = non_null.all(|v| std::mem::discriminant(v) == first_elems_type);
 
             if !is_all_same {
                 return Err(error::Error::ListTypeError);
@@ -344,11 +589,11 @@ impl TryFrom<PtrContainer<ffi::kuzu_value>> for VarList {
 impl VarList {
     #[inline]
     fn try_new(inner: Vec<KuzuValue>) -> error::Result<Self> {
-        if let Some(first_elem) = inner.get(0) {
+        // NULL elements are allowed alongside values of any type.
+        let mut non_null = inner.iter().filter(|v| !matches!(v, KuzuValue::Null(_)));
+        if let Some(first_elem) = non_null.next() {
             let first_elems_type = std::mem::discriminant(first_elem);
-            let is_all_same = inner
-                .iter()
-                .all(|v| std::mem::discriminant(v) == first_elems_type);
+            let is_all_same = non_null.all(|v| std::mem::discriminant(v) == first_elems_type);
 
             if !is_all_same {
                 return Err(error::Error::ListTypeError);
@@ -360,6 +605,9 @@ impl VarList {
 }
 
 /// Represents the struct datatype.
+///
+/// Kuzu uppercases the names of struct fields, so the field names are case-insensitive:
+/// `get` and `FromIterator` uppercase them as well.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Struct {
     /// The inner hashmap of Kuzu values.
@@ -367,13 +615,25 @@ pub struct Struct {
 }
 
 impl Struct {
-    /// Retrieves the KuzuValue associated with the specified key, if it exists.
+    /// Retrieves the KuzuValue associated with the specified key, if it exists, ignoring the case of the key.
     pub fn get<S: AsRef<str>>(&self, key: S) -> Option<&KuzuValue> {
         let upper_key = key.as_ref().to_uppercase();
         self.inner.get(&upper_key)
     }
 }
 
+impl FromIterator<(String, KuzuValue)> for Struct {
+    /// Creates a struct from its fields, uppercasing the field names like Kuzu does.
+    fn from_iter<I: IntoIterator<Item = (String, KuzuValue)>>(iter: I) -> Self {
+        Self {
+            inner: iter
+                .into_iter()
+                .map(|(key, val)| (key.to_uppercase(), val))
+                .collect(),
+        }
+    }
+}
+
 impl TryFrom<PtrContainer<ffi::kuzu_value>> for Struct {
     type Error = error::Error;
     fn try_from(value: PtrContainer<ffi::kuzu_value>) -> Result<Self, Self::Error> {
@@ -414,7 +674,10 @@ impl TryFrom<PtrContainer<ffi::kuzu_value>> for Struct {
 }
 #[cfg(test)]
 pub mod tests {
-    use super::{FixedList, InternalId, Node, Relation, VarList};
+    use std::collections::HashMap;
+
+    use super::{FixedList, InternalId, KuzuValue, Node, Relation, VarList};
+    use crate::{ffi, ptrc::PtrContainer};
 
     pub fn new_internal_id(offset: usize, table_id: usize) -> InternalId {
         InternalId { offset, table_id }
@@ -451,4 +714,42 @@ pub mod tests {
             len: 0,
         }
     }
+
+    /// Encodes the value for the C API and decodes it back.
+    fn round_trip(value: &KuzuValue) -> KuzuValue {
+        let encoded = PtrContainer::<ffi::kuzu_value>::try_from(value).unwrap();
+        KuzuValue::try_from(encoded).unwrap()
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        let id = KuzuValue::InternalId(new_internal_id(3, 1));
+        assert_eq!(round_trip(&id), id);
+
+        let properties = HashMap::from([
+            ("name".to_owned(), KuzuValue::String("Alice".to_owned())),
+            ("age".to_owned(), KuzuValue::Int64(35)),
+        ]);
+        let node = Node {
+            id: new_internal_id(3, 1),
+            label: "User".to_owned(),
+            properties: properties.clone(),
+        };
+        match round_trip(&KuzuValue::Node(node.clone())) {
+            KuzuValue::Node(decoded) => {
+                assert_eq!(decoded.id(), node.id());
+                assert_eq!(decoded.label(), "User");
+                assert_eq!(decoded.properties(), &properties);
+            }
+            value => panic!("expected a node, got {value:?}"),
+        }
+
+        let rel = KuzuValue::Rel(Relation::new(
+            "Follows",
+            new_internal_id(3, 1),
+            new_internal_id(4, 1),
+            HashMap::from([("since".to_owned(), KuzuValue::Int64(2020))]),
+        ));
+        assert_eq!(round_trip(&rel), rel);
+    }
 }